@@ -0,0 +1,277 @@
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    hint::black_box,
+    io::{ErrorKind, Write},
+    path::Path,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use aoc2023::solver;
+
+use crate::{parse_number, USAGE};
+
+const HISTORY_HEADER: &str = "timestamp,day,parse_ns,part1_ns,part2_ns";
+const STAGES: [&str; 3] = ["parse", "part 1", "part 2"];
+
+/// Stages that got slower by less than this are never reported, their timings are mostly noise.
+const MIN_REGRESSION: Duration = Duration::from_millis(1);
+
+pub struct BenchArgs {
+    day: Option<u8>,
+    runs: u32,
+    history: String,
+    threshold: u32,
+}
+
+/// Best time of parse, part 1 and part 2.
+type Timing = [Duration; 3];
+
+pub fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs {
+        day: None,
+        runs: 1,
+        history: "bench_history.csv".to_string(),
+        threshold: 25,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {arg}\n\n{USAGE}"))
+        };
+        match arg.as_str() {
+            "--day" => bench_args.day = Some(parse_number(value()?, 1..=25, "day")?),
+            "--runs" => bench_args.runs = parse_number(value()?, 1..=1000, "number of runs")?,
+            "--history" => bench_args.history = value()?.clone(),
+            "--threshold" => bench_args.threshold = parse_number(value()?, 0..=1000, "threshold")?,
+            _ => return Err(format!("Unknown argument: {arg}\n\n{USAGE}")),
+        }
+    }
+    Ok(bench_args)
+}
+
+/// Times every day that has an input, appends the timings to the history file and fails if any
+/// stage got slower than in the previous run of that day.
+pub fn bench(args: BenchArgs) -> Result<(), String> {
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => (1..=25).collect(),
+    };
+    let previous = load_history(&args.history)?;
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| e.to_string())?
+        .as_secs();
+
+    println!("Day      Parse     Part 1     Part 2");
+    let mut timings = Vec::new();
+    let mut regressions = Vec::new();
+    for day in days {
+        let input_path = format!("input/day{day:02}.txt");
+        if args.day.is_none() && !Path::new(&input_path).exists() {
+            println!("{day:>3}  skipped, {input_path} not found");
+            continue;
+        }
+        let input = crate::read_input(&input_path)?;
+        let timing = time_day(day, &input, args.runs)?;
+        let columns: Vec<String> = timing.iter().map(|&time| format_duration(time)).collect();
+        println!("{day:>3} {}", columns.join(" "));
+
+        if let Some(previous_timing) = previous.get(&day) {
+            for (stage, (&time, &previous_time)) in timing.iter().zip(previous_timing).enumerate() {
+                if is_regression(time, previous_time, args.threshold) {
+                    regressions.push(format!(
+                        "Day {day} {}: {} -> {}",
+                        STAGES[stage],
+                        format_duration(previous_time).trim(),
+                        format_duration(time).trim()
+                    ));
+                }
+            }
+        }
+        timings.push((day, timing));
+    }
+
+    append_history(&args.history, timestamp, &timings)?;
+    if regressions.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Slower than the previous run by more than {}%:\n{}",
+            args.threshold,
+            regressions.join("\n")
+        ))
+    }
+}
+
+fn time_day(day: u8, input: &str, runs: u32) -> Result<Timing, String> {
+    let solver = solver(day).ok_or_else(|| format!("There's no solution for day {day}"))?;
+    let mut best = [Duration::MAX; 3];
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = solver.parse(input).map_err(|e| e.to_string())?;
+        let parsed_at = Instant::now();
+        black_box(solver.part1(&*parsed));
+        let part1_at = Instant::now();
+        black_box(solver.part2(&*parsed));
+        let part2_at = Instant::now();
+
+        let timing = [parsed_at - start, part1_at - parsed_at, part2_at - part1_at];
+        for (best_time, time) in best.iter_mut().zip(timing) {
+            *best_time = (*best_time).min(time);
+        }
+    }
+    Ok(best)
+}
+
+fn is_regression(time: Duration, previous_time: Duration, threshold_percent: u32) -> bool {
+    let allowed = previous_time + previous_time * threshold_percent / 100;
+    time > allowed && time - previous_time >= MIN_REGRESSION
+}
+
+fn format_duration(time: Duration) -> String {
+    format!("{:>7.2} ms", time.as_secs_f64() * 1000.0)
+}
+
+/// Returns the most recent timing of every day in the history file.
+fn load_history(path: &str) -> Result<HashMap<u8, Timing>, String> {
+    let history = match fs::read_to_string(path) {
+        Ok(history) => history,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(e) => return Err(format!("Can't read {path}: {e}")),
+    };
+    parse_history(path, &history)
+}
+
+/// Parses the entries of a history file, where `path` is only used in error messages.
+fn parse_history(path: &str, history: &str) -> Result<HashMap<u8, Timing>, String> {
+    let mut lines = history.lines();
+    match lines.next() {
+        None => return Ok(HashMap::new()),
+        Some(HISTORY_HEADER) => {}
+        Some(header) => {
+            return Err(format!(
+                "{path}: expected the header {HISTORY_HEADER:?}, got {header:?}"
+            ))
+        }
+    }
+    let mut latest = HashMap::new();
+    for (i, line) in lines.enumerate() {
+        let invalid = || format!("{path}, line {}: invalid history entry: {line:?}", i + 2);
+        let fields: Vec<u64> = line
+            .split(',')
+            .map(|field| field.parse().map_err(|_| invalid()))
+            .collect::<Result<_, _>>()?;
+        let [_, day, parse_ns, part1_ns, part2_ns] = fields[..] else {
+            return Err(invalid());
+        };
+        let day = u8::try_from(day).map_err(|_| invalid())?;
+        latest.insert(
+            day,
+            [parse_ns, part1_ns, part2_ns].map(Duration::from_nanos),
+        );
+    }
+    Ok(latest)
+}
+
+fn append_history(path: &str, timestamp: u64, timings: &[(u8, Timing)]) -> Result<(), String> {
+    let is_new = fs::metadata(path).map_or(true, |metadata| metadata.len() == 0);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Can't open {path}: {e}"))?;
+    let mut entries = String::new();
+    if is_new {
+        entries.push_str(HISTORY_HEADER);
+        entries.push('\n');
+    }
+    for (day, timing) in timings {
+        let [parse, part1, part2] = timing.map(|time| time.as_nanos());
+        entries.push_str(&format!("{timestamp},{day},{parse},{part1},{part2}\n"));
+    }
+    file.write_all(entries.as_bytes())
+        .map_err(|e| format!("Can't write {path}: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regression_threshold() {
+        let ms = Duration::from_millis;
+        assert!(!is_regression(ms(125), ms(100), 25));
+        assert!(is_regression(
+            ms(125) + Duration::from_nanos(1),
+            ms(100),
+            25
+        ));
+        assert!(!is_regression(ms(90), ms(100), 0));
+        assert!(!is_regression(ms(100), ms(100), 0));
+        assert!(is_regression(ms(101), ms(100), 0));
+        // Slowdowns under a millisecond are noise, however large they are relatively
+        assert!(!is_regression(
+            Duration::from_micros(900),
+            Duration::from_micros(10),
+            25
+        ));
+        assert!(is_regression(ms(1), Duration::ZERO, 1000));
+    }
+
+    #[test]
+    fn history_entries() {
+        let history = format!("{HISTORY_HEADER}\n1,3,10,20,30\n1,5,1,2,3\n2,3,40,50,60\n");
+        let latest = parse_history("history.csv", &history).unwrap();
+        assert_eq!(latest.len(), 2);
+        assert_eq!(latest[&3], [40, 50, 60].map(Duration::from_nanos));
+        assert_eq!(latest[&5], [1, 2, 3].map(Duration::from_nanos));
+        assert!(parse_history("history.csv", "").unwrap().is_empty());
+        assert!(parse_history("history.csv", HISTORY_HEADER)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn malformed_history() {
+        let error = |history: &str| parse_history("history.csv", history).unwrap_err();
+        assert_eq!(
+            error("1,3,10,20,30\n"),
+            format!("history.csv: expected the header {HISTORY_HEADER:?}, got \"1,3,10,20,30\"")
+        );
+        let entry_error =
+            |entry: &str| error(&format!("{HISTORY_HEADER}\n1,3,10,20,30\n{entry}\n"));
+        let invalid =
+            |entry: &str| format!("history.csv, line 3: invalid history entry: {entry:?}");
+        for entry in [
+            "1,3,10,20",
+            "1,3,10,20,30,40",
+            "1,3,ten,20,30",
+            "1,300,10,20,30",
+            "1,3,-1,20,30",
+            "",
+        ] {
+            assert_eq!(entry_error(entry), invalid(entry));
+        }
+    }
+
+    #[test]
+    fn history_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-bench-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("history.csv");
+        let path = path.to_str().unwrap();
+
+        assert!(load_history(path).unwrap().is_empty());
+        fs::write(path, "").unwrap();
+        assert!(load_history(path).unwrap().is_empty());
+        let timing = [1, 2, 3].map(Duration::from_nanos);
+        append_history(path, 100, &[(7, timing)]).unwrap();
+        append_history(path, 200, &[(7, timing.map(|time| time * 2))]).unwrap();
+        let latest = load_history(path).unwrap();
+        assert_eq!(latest[&7], [2, 4, 6].map(Duration::from_nanos));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    env,
+    fmt::Display,
+    fs::{read_to_string, write},
+    io::{self, Read},
+    ops::RangeInclusive,
+    process::ExitCode,
+    str::FromStr,
+};
+
+use aoc2023::solver;
+
+mod bench;
+
+const USAGE: &str =
+    "Usage: aoc run --day <1-25> [--part <1|2>] [--input <path|->] [--set <name>=<value>]...
+               [--emit-dot <path>]
+       aoc bench [--day <1-25>] [--runs <n>] [--history <path>] [--threshold <percent>]
+
+run solves the puzzle of the given day. Without --part both parts are solved. The input is read
+from the given file, from standard input if the path is '-', or from input/dayNN.txt by default.
+--set changes a setting of the solver, like steps=6 for day 21 or show_route=true for day 17.
+--emit-dot writes the graph described by the input as a Graphviz DOT file, for days 20, 23 and
+25.
+
+bench times parsing and both parts of the given day, or of every day with an input in input/.
+The best time of --runs runs (1 by default) is appended to the history file (bench_history.csv
+by default). Stages that got more than --threshold percent (25 by default) slower than in the
+previous run of that day are reported as regressions.";
+
+struct RunArgs {
+    day: u8,
+    part: Option<u8>,
+    input: Option<String>,
+    settings: Vec<(String, String)>,
+    dot_path: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    match args.first().map(String::as_str) {
+        Some("run") => {
+            let RunArgs { day, part, input, settings, dot_path } = parse_run_args(&args[1..])?;
+            let mut solver =
+                solver(day).ok_or_else(|| format!("There's no solution for day {day}"))?;
+            for (name, value) in settings {
+                solver.set(&name, &value)?;
+            }
+            let input_path = input.unwrap_or_else(|| format!("input/day{day:02}.txt"));
+            let input = read_input(&input_path)?;
+            let parsed = solver.parse(&input).map_err(|e| e.to_string())?;
+            if let Some(dot_path) = dot_path {
+                let dot = solver
+                    .to_dot(&*parsed)
+                    .ok_or_else(|| format!("Day {day} has no graph to emit"))?;
+                write(&dot_path, dot).map_err(|e| format!("Can't write {dot_path}: {e}"))?;
+            }
+            if part != Some(2) {
+                println!("Part 1: {}", solver.part1(&*parsed));
+            }
+            if part != Some(1) {
+                println!("Part 2: {}", solver.part2(&*parsed));
+            }
+            Ok(())
+        }
+        Some("bench") => bench::bench(bench::parse_bench_args(&args[1..])?),
+        Some("--help" | "-h") => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut settings = Vec::new();
+    let mut dot_path = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {arg}\n\n{USAGE}"))
+        };
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(value()?, 1..=25, "day")?),
+            "--part" => part = Some(parse_number(value()?, 1..=2, "part")?),
+            "--input" => input = Some(value()?.clone()),
+            "--set" => {
+                let setting = value()?;
+                let (name, value) = setting
+                    .split_once('=')
+                    .ok_or_else(|| format!("Expected <name>=<value>: {setting}\n\n{USAGE}"))?;
+                settings.push((name.to_string(), value.to_string()));
+            }
+            "--emit-dot" => dot_path = Some(value()?.clone()),
+            _ => return Err(format!("Unknown argument: {arg}\n\n{USAGE}")),
+        }
+    }
+    let day = day.ok_or_else(|| format!("Missing --day\n\n{USAGE}"))?;
+    Ok(RunArgs { day, part, input, settings, dot_path })
+}
+
+fn parse_number<T>(value: &str, range: RangeInclusive<T>, name: &str) -> Result<T, String>
+where
+    T: FromStr + PartialOrd + Display,
+{
+    value
+        .parse()
+        .ok()
+        .filter(|n| range.contains(n))
+        .ok_or_else(|| {
+            format!(
+                "Invalid {name}: {value}, expected {}-{}",
+                range.start(),
+                range.end()
+            )
+        })
+}
+
+fn read_input(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("Can't read standard input: {e}"))?;
+        Ok(input)
+    } else {
+        read_to_string(path).map_err(|e| format!("Can't read {path}: {e}"))
+    }
+}
@@ -55,9 +55,8 @@ fn num_arrangements(record: &[AsciiChar], group_sizes: &[Count]) -> usize {
     let extra_gap_count = record.len() as Count - (groups_plus_gaps - 1);
     let placement_count = group_sizes.len() as Count + 1;
     let mut num_valid_arrangements = 0;
-    for gap_arrangement in gap_arrangements(extra_gap_count, placement_count, &group_sizes, &record)
-    {
-        if is_valid_arrangement(&group_sizes, &gap_arrangement, &record) {
+    for gap_arrangement in gap_arrangements(extra_gap_count, placement_count, group_sizes, record) {
+        if is_valid_arrangement(group_sizes, &gap_arrangement, record) {
             num_valid_arrangements += 1;
         }
     }
@@ -110,7 +109,7 @@ fn gap_arrangements(
 ) -> Vec<Vec<Count>> {
     if num_buckets == 1 {
         let gap_arrangement = vec![num_items as Count];
-        if is_valid_arrangement(&group_sizes[0..1], &gap_arrangement, &record) {
+        if is_valid_arrangement(&group_sizes[0..1], &gap_arrangement, record) {
             return vec![gap_arrangement];
         } else {
             return Vec::new();
@@ -129,11 +128,11 @@ fn gap_arrangements(
             if is_valid_arrangement(
                 &group_sizes[0..min(gap_arrangement.len(), group_sizes.len())],
                 &gap_arrangement,
-                &record,
+                record,
             ) {
                 result.push(gap_arrangement);
             }
         }
     }
-    return result;
+    result
 }
@@ -0,0 +1,130 @@
+use std::fs::read_to_string;
+
+use aho_corasick::AhoCorasick;
+
+use crate::{
+    parse::{self, ParseError},
+    Fallible, Solution,
+};
+
+const DAY: u8 = 1;
+const ENGLISH: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub struct Day01 {
+    /// The spelled out digits that count in part 2, besides the digits themselves.
+    pub vocabulary: Vec<(String, usize)>,
+}
+
+impl Default for Day01 {
+    fn default() -> Self {
+        let vocabulary = ENGLISH
+            .iter()
+            .zip(1..)
+            .map(|(word, digit)| (word.to_string(), digit))
+            .collect();
+        Day01 { vocabulary }
+    }
+}
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    type Answer1 = Fallible<usize>;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let calibrator = Calibrator::new(&self.vocabulary);
+        parse::lines(DAY, input)
+            .map(|line| match calibrator.digits(line.text).is_empty() {
+                false => Ok(line.text.to_string()),
+                true => Err(line.error(line.text, "expected a digit")),
+            })
+            .collect()
+    }
+
+    /// Lines with only spelled out digits are fine for part 2, but have no value in part 1.
+    fn part1(&self, input_lines: &Self::Input) -> Fallible<usize> {
+        calibration_sum(input_lines, &Calibrator::new(&[])).into()
+    }
+
+    fn part2(&self, input_lines: &Self::Input) -> usize {
+        calibration_sum(input_lines, &Calibrator::new(&self.vocabulary))
+            .expect("parse checked the digits")
+    }
+
+    /// `vocabulary` is the path of a file with a word and its digit on each line, like
+    /// `zwei 2`, which replace the English words.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "vocabulary" => self.vocabulary = load_vocabulary(value)?,
+            _ => return Err(format!("Unknown setting: {name}")),
+        }
+        Ok(())
+    }
+}
+
+fn load_vocabulary(path: &str) -> Result<Vec<(String, usize)>, String> {
+    let text = read_to_string(path).map_err(|e| format!("Can't read {path}: {e}"))?;
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let mut fields = line.split_whitespace();
+            match (fields.next(), fields.next().map(str::parse), fields.next()) {
+                (Some(word), Some(Ok(digit)), None) => Ok((word.to_string(), digit)),
+                _ => Err(format!("{path}, line {}: expected <word> <digit>", i + 1)),
+            }
+        })
+        .collect()
+}
+
+/// Finds the digits 0 to 9 and the words of a vocabulary in a line in one pass, including words
+/// that overlap, like the `two` and `one` of `twone`.
+pub struct Calibrator {
+    automaton: AhoCorasick,
+    digits: Vec<usize>,
+}
+
+impl Calibrator {
+    pub fn new(vocabulary: &[(String, usize)]) -> Self {
+        let (mut patterns, mut digits): (Vec<String>, Vec<usize>) =
+            (0..10).map(|digit| (digit.to_string(), digit)).unzip();
+        for (word, digit) in vocabulary {
+            patterns.push(word.clone());
+            digits.push(*digit);
+        }
+        let automaton = AhoCorasick::new(patterns).expect("too many words for the automaton");
+        Calibrator { automaton, digits }
+    }
+
+    /// The position and value of every digit in `line`, in order.
+    pub fn digits(&self, line: &str) -> Vec<(usize, usize)> {
+        let mut digits: Vec<(usize, usize)> = self
+            .automaton
+            .find_overlapping_iter(line)
+            .map(|m| (m.start(), self.digits[m.pattern()]))
+            .collect();
+        digits.sort();
+        digits
+    }
+
+    /// The first digit times ten plus the last digit, if there are any digits.
+    pub fn value(&self, line: &str) -> Option<usize> {
+        let digits = self.digits(line);
+        let ((_, first), (_, last)) = (digits.first()?, digits.last()?);
+        Some(10 * first + last)
+    }
+}
+
+fn calibration_sum(input_lines: &[String], calibrator: &Calibrator) -> Result<usize, String> {
+    input_lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            calibrator
+                .value(line)
+                .ok_or_else(|| format!("Line {} has no digit", i + 1))
+        })
+        .sum()
+}
@@ -0,0 +1,152 @@
+use std::{collections::HashMap, fs::read_to_string, sync::OnceLock};
+
+use regex::Regex;
+
+use crate::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 2;
+
+pub struct Day02 {
+    /// The bag of part 1.
+    pub bag: Bag,
+}
+
+impl Default for Day02 {
+    fn default() -> Self {
+        let bag = [("red", 12), ("green", 13), ("blue", 14)]
+            .map(|(color, count)| (color.to_string(), count))
+            .into();
+        Day02 { bag }
+    }
+}
+
+pub type Color = String;
+
+/// How many cubes of each color are in a bag. Missing colors have no cubes.
+pub type Bag = HashMap<Color, usize>;
+
+pub struct Game {
+    pub id: usize,
+    pub draws: Vec<HashMap<Color, usize>>,
+}
+
+impl Game {
+    pub fn is_possible_with(&self, bag: &Bag) -> bool {
+        self.draws.iter().all(|draw| contains(bag, draw))
+    }
+
+    /// The smallest bag the game is possible with.
+    pub fn minimum_bag(&self) -> Bag {
+        let mut bag = Bag::new();
+        for draw in &self.draws {
+            for (color, &count) in draw {
+                let max_count = bag.entry(color.clone()).or_default();
+                *max_count = count.max(*max_count);
+            }
+        }
+        bag
+    }
+
+    /// The product of the cube counts of the smallest bag the game is possible with.
+    pub fn power(&self) -> usize {
+        self.minimum_bag().values().product()
+    }
+}
+
+/// Whether `bag` has at least as many cubes of every color as `cubes`.
+fn contains(bag: &Bag, cubes: &HashMap<Color, usize>) -> bool {
+    cubes
+        .iter()
+        .all(|(color, &count)| count <= bag.get(color).copied().unwrap_or(0))
+}
+
+/// The smallest bag that makes exactly the games with the given ids possible, if there is one.
+/// Every such bag contains this one, as it has just enough cubes for the chosen games, so if
+/// it also makes another game possible, no bag can do it.
+pub fn exact_bag(games: &[Game], ids: &[usize]) -> Option<Bag> {
+    let mut bag = Bag::new();
+    for game in games.iter().filter(|game| ids.contains(&game.id)) {
+        for (color, count) in game.minimum_bag() {
+            let max_count = bag.entry(color).or_default();
+            *max_count = count.max(*max_count);
+        }
+    }
+    games
+        .iter()
+        .all(|game| game.is_possible_with(&bag) == ids.contains(&game.id))
+        .then_some(bag)
+}
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let game_re = Regex::new(r"^Game (\d+): (.*)$").unwrap();
+        parse::lines(DAY, input)
+            .map(|line| {
+                let header_match = line.captures(&game_re)?;
+                let id = line.number(&header_match[1])?;
+                let draws = header_match
+                    .get(2)
+                    .unwrap()
+                    .as_str()
+                    .split(';')
+                    .map(|draw_str| parse_cubes(&line, draw_str))
+                    .collect::<Result<_, _>>()?;
+                Ok(Game { id, draws })
+            })
+            .collect()
+    }
+
+    fn part1(&self, games: &Self::Input) -> usize {
+        games
+            .iter()
+            .filter(|game| game.is_possible_with(&self.bag))
+            .map(|game| game.id)
+            .sum()
+    }
+
+    fn part2(&self, games: &Self::Input) -> usize {
+        games.iter().map(Game::power).sum()
+    }
+
+    /// `bag` is given like a draw, e.g. `12 red, 13 green, 14 blue`. `bag_file` is the path of
+    /// a file containing such a bag.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let bag_str = match name {
+            "bag" => value.to_string(),
+            "bag_file" => read_to_string(value).map_err(|e| format!("Can't read {value}: {e}"))?,
+            _ => return Err(format!("Unknown setting: {name}")),
+        };
+        let line = Line { day: DAY, number: 1, text: bag_str.trim() };
+        self.bag = parse_cubes(&line, line.text)
+            .map_err(|e| format!("Invalid bag: {}: {:?}", e.message, e.text))?;
+        Ok(())
+    }
+}
+
+/// Parses cubes like `3 blue, 4 red`.
+fn parse_cubes(line: &Line, cubes_str: &str) -> Result<HashMap<Color, usize>, ParseError> {
+    let mut cubes = HashMap::new();
+    for color_count_str in cubes_str.split(',') {
+        let groups = cubes_re()
+            .captures(color_count_str)
+            .ok_or_else(|| line.error(color_count_str, "expected a count and a color"))?;
+        let count = line.number(groups.get(1).unwrap().as_str())?;
+        let color_str = groups.get(2).unwrap().as_str();
+        if cubes.insert(color_str.to_string(), count).is_some() {
+            return Err(line.error(color_str, "expected every color once"));
+        }
+    }
+    Ok(cubes)
+}
+
+fn cubes_re() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"^ *(\d+) (\w+) *$").unwrap())
+}
@@ -0,0 +1,217 @@
+use std::{collections::HashMap, ops::Range, sync::OnceLock};
+
+use regex::bytes::Regex;
+
+use crate::{
+    grid::{Grid, Pos},
+    parse::{self, Line, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 3;
+
+#[derive(Default)]
+pub struct Day03 {
+    pub gear_rule: GearRule,
+}
+
+/// Which symbols are gears in part 2 and what their gear ratio is: a symbol from `symbols` next
+/// to exactly `neighbor_count` part numbers is a gear, and its ratio combines their values.
+#[derive(Clone)]
+pub struct GearRule {
+    pub symbols: Vec<u8>,
+    pub neighbor_count: usize,
+    pub combine: fn(&[usize]) -> usize,
+}
+
+impl Default for GearRule {
+    /// A `*` next to two part numbers, with the product of them as its ratio.
+    fn default() -> Self {
+        GearRule {
+            symbols: b"*".to_vec(),
+            neighbor_count: 2,
+            combine: |values| values.iter().product(),
+        }
+    }
+}
+
+/// A number in the schematic, in row `y` and the columns `x`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PartNumber {
+    pub value: usize,
+    pub span: Span,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Span {
+    pub y: usize,
+    pub x: Range<usize>,
+}
+
+/// Anything in the schematic that's neither a digit nor a `.`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Symbol {
+    pub char: u8,
+    pub pos: Pos,
+}
+
+/// The numbers and symbols of the engine schematic, and which of them are next to each other.
+pub struct Schematic {
+    numbers: Vec<PartNumber>,
+    symbols: Vec<Symbol>,
+    symbols_next_to_number: Vec<Vec<usize>>,
+    numbers_next_to_symbol: Vec<Vec<usize>>,
+}
+
+impl Solution for Day03 {
+    type Input = Schematic;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let printable: Vec<u8> = (b'!'..=b'~').collect();
+        let grid = parse::grid(DAY, input, &printable)?;
+        let grid_lines: Vec<Line> = parse::lines(DAY, input).collect();
+        Schematic::new(&grid_lines, &grid)
+    }
+
+    fn part1(&self, schematic: &Self::Input) -> usize {
+        schematic.part_numbers().map(|number| number.value).sum()
+    }
+
+    fn part2(&self, schematic: &Self::Input) -> usize {
+        schematic.gear_ratios(&self.gear_rule).sum()
+    }
+
+    /// `gear_symbols` and `gear_neighbor_count` change the gear rule, `gear_combine` is
+    /// `product`, `sum` or `max`.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let rule = &mut self.gear_rule;
+        match name {
+            "gear_symbols" => rule.symbols = value.bytes().collect(),
+            "gear_neighbor_count" => rule.neighbor_count = parse::setting(name, value)?,
+            "gear_combine" => {
+                rule.combine = match value {
+                    "product" => |values| values.iter().product(),
+                    "sum" => |values| values.iter().sum(),
+                    "max" => |values| values.iter().copied().max().unwrap_or(0),
+                    _ => return Err(format!("Invalid value for {name}: {value:?}")),
+                }
+            }
+            _ => return Err(format!("Unknown setting: {name}")),
+        }
+        Ok(())
+    }
+}
+
+impl Schematic {
+    /// Finds the numbers and symbols of `grid`, which was parsed from `grid_lines`.
+    fn new(grid_lines: &[Line], grid: &Grid<u8>) -> Result<Self, ParseError> {
+        let mut numbers = Vec::new();
+        for (y, line) in grid_lines.iter().enumerate() {
+            for m in num_re().find_iter(line.text.as_bytes()) {
+                let span = Span { y, x: m.range() };
+                let value = part_number(line, &line.text[m.range()])?;
+                numbers.push(PartNumber { value, span });
+            }
+        }
+        let symbols: Vec<Symbol> = grid
+            .iter()
+            .filter(|&(_, &char)| is_symbol(char))
+            .map(|(pos, &char)| Symbol { char, pos })
+            .collect();
+        let symbol_at: HashMap<Pos, usize> = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, symbol)| (symbol.pos, i))
+            .collect();
+        let mut symbols_next_to_number = vec![Vec::new(); numbers.len()];
+        let mut numbers_next_to_symbol = vec![Vec::new(); symbols.len()];
+        for (i, number) in numbers.iter().enumerate() {
+            for pos in neighbor_places(grid, &number.span) {
+                if let Some(&j) = symbol_at.get(&pos) {
+                    symbols_next_to_number[i].push(j);
+                    numbers_next_to_symbol[j].push(i);
+                }
+            }
+        }
+        Ok(Schematic {
+            numbers,
+            symbols,
+            symbols_next_to_number,
+            numbers_next_to_symbol,
+        })
+    }
+
+    /// All numbers, row by row.
+    pub fn numbers(&self) -> &[PartNumber] {
+        &self.numbers
+    }
+
+    /// All symbols, row by row.
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// The symbols next to the number with the given index in [`Self::numbers`].
+    pub fn symbols_next_to(&self, number: usize) -> impl Iterator<Item = &Symbol> {
+        self.symbols_next_to_number[number]
+            .iter()
+            .map(|&i| &self.symbols[i])
+    }
+
+    /// The numbers next to the symbol with the given index in [`Self::symbols`].
+    pub fn numbers_next_to(&self, symbol: usize) -> impl Iterator<Item = &PartNumber> {
+        self.numbers_next_to_symbol[symbol]
+            .iter()
+            .map(|&i| &self.numbers[i])
+    }
+
+    /// The numbers next to a symbol, which are the actual part numbers.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &PartNumber> {
+        (0..self.numbers.len())
+            .filter(|&i| !self.symbols_next_to_number[i].is_empty())
+            .map(|i| &self.numbers[i])
+    }
+
+    /// The ratios of the gears according to `rule`, in the order of the gears.
+    pub fn gear_ratios<'a>(&'a self, rule: &'a GearRule) -> impl Iterator<Item = usize> + 'a {
+        (0..self.symbols.len())
+            .filter(|&i| rule.symbols.contains(&self.symbols[i].char))
+            .map(|i| {
+                self.numbers_next_to(i)
+                    .map(|number| number.value)
+                    .collect::<Vec<usize>>()
+            })
+            .filter(|values| values.len() == rule.neighbor_count)
+            .map(|values| (rule.combine)(&values))
+    }
+}
+
+fn num_re() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"\d+").unwrap())
+}
+
+/// The places around the digits of `span`.
+fn neighbor_places<'a>(grid: &'a Grid<u8>, span: &Span) -> impl Iterator<Item = Pos> + 'a {
+    let (y, start_x) = (span.y, span.x.start);
+    let len = span.x.len() as isize;
+    (-1..=1)
+        .flat_map(move |dy| (-1..=len).map(move |dx| (dy, dx)))
+        .filter(move |&(dy, dx)| dy != 0 || dx == -1 || dx == len)
+        .filter_map(move |delta| grid.offset((y, start_x), delta))
+}
+
+fn part_number(line: &Line, digits: &str) -> Result<usize, ParseError> {
+    digits
+        .bytes()
+        .try_fold(0usize, |acc, digit| {
+            acc.checked_mul(10)?.checked_add((digit - b'0') as usize)
+        })
+        .ok_or_else(|| line.error(digits, "expected a number that fits into a usize"))
+}
+
+fn is_symbol(ch: u8) -> bool {
+    !ch.is_ascii_digit() && ch != b'.'
+}
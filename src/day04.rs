@@ -0,0 +1,137 @@
+use std::{collections::HashSet, fmt};
+
+use crate::{
+    parse::{self, Line, ParseError},
+    Fallible, Solution,
+};
+
+const DAY: u8 = 4;
+
+#[derive(Default)]
+pub struct Day04 {
+    /// Show how many copies of each card part 2 ends up with after the answer.
+    pub show_copies: bool,
+}
+
+pub struct Card {
+    pub id: usize,
+    pub winning: HashSet<usize>,
+    pub mine: HashSet<usize>,
+}
+
+impl Card {
+    /// How many of my numbers are winning numbers.
+    pub fn matches(&self) -> usize {
+        self.winning.intersection(&self.mine).count()
+    }
+
+    /// One point for the first match, doubled for every further one. Returns `None` if they
+    /// don't fit into a `usize`.
+    pub fn points(&self) -> Option<usize> {
+        match self.matches() {
+            0 => Some(0),
+            n => 1usize.checked_shl((n - 1) as u32),
+        }
+    }
+}
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+    type Answer1 = Fallible<usize>;
+    type Answer2 = Copies;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(DAY, input)
+            .map(|line| load_card(&line))
+            .collect()
+    }
+
+    fn part1(&self, cards: &Self::Input) -> Fallible<usize> {
+        total_points(cards).into()
+    }
+
+    fn part2(&self, cards: &Self::Input) -> Copies {
+        let counts = copy_counts(cards);
+        Copies {
+            total: counts.iter().sum(),
+            counts: self
+                .show_copies
+                .then(|| cards.iter().map(|card| card.id).zip(counts).collect()),
+        }
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "show_copies" => self.show_copies = parse::setting(name, value)?,
+            _ => return Err(format!("Unknown setting: {name}")),
+        }
+        Ok(())
+    }
+}
+
+fn total_points(cards: &[Card]) -> Result<usize, String> {
+    cards.iter().try_fold(0usize, |sum, card| {
+        let points = card
+            .points()
+            .ok_or_else(|| format!("The points of card {} don't fit into a usize", card.id))?;
+        sum.checked_add(points)
+            .ok_or_else(|| "The sum of the points doesn't fit into a usize".to_string())
+    })
+}
+
+/// How many copies of each card there are in the end, including the original. Every copy of a
+/// card with `n` matches wins a copy of each of the next `n` cards, as far as there are any.
+pub fn copy_counts(cards: &[Card]) -> Vec<usize> {
+    let mut counts = vec![1; cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        let end = (i + 1 + card.matches()).min(cards.len());
+        let count = counts[i];
+        for won in &mut counts[i + 1..end] {
+            *won += count;
+        }
+    }
+    counts
+}
+
+pub struct Copies {
+    pub total: usize,
+    /// The card ids and their copy counts, if they are shown.
+    counts: Option<Vec<(usize, usize)>>,
+}
+
+impl fmt::Display for Copies {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.total)?;
+        for (id, count) in self.counts.iter().flatten() {
+            write!(f, "\nCard {id}: {count}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a card like `Card 1: 41 48 | 83 86  6`, with any amount of whitespace between the
+/// numbers.
+fn load_card(line: &Line) -> Result<Card, ParseError> {
+    let (header, numbers) = line.split_once(line.text, ":")?;
+    let id_str = header
+        .strip_prefix("Card")
+        .filter(|id_str| id_str.starts_with(char::is_whitespace))
+        .ok_or_else(|| line.error(header, "expected \"Card <id>\""))?;
+    let id = line.number(id_str.trim())?;
+    let (winning_str, mine_str) = line.split_once(numbers, "|")?;
+    Ok(Card {
+        id,
+        winning: number_set(line, winning_str)?,
+        mine: number_set(line, mine_str)?,
+    })
+}
+
+fn number_set(line: &Line, numbers_str: &str) -> Result<HashSet<usize>, ParseError> {
+    let mut numbers = HashSet::new();
+    for number in line.numbers(numbers_str)? {
+        if !numbers.insert(number) {
+            return Err(line.error(numbers_str, format!("expected {number} only once")));
+        }
+    }
+    Ok(numbers)
+}
@@ -0,0 +1,377 @@
+use std::{fmt, ops::Range};
+
+use crate::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 5;
+
+#[derive(Default)]
+pub struct Day05 {
+    /// Show the composed seed to location mapping after the answer of part 2.
+    pub show_mapping: bool,
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    mappings: Vec<Mapping>,
+}
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Answer1 = i64;
+    type Answer2 = NearestLocation;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let blocks = parse::blocks(DAY, input);
+        let Some(seeds_line) = blocks.first().map(|block| block[0]) else {
+            return Err(parse::end_of_input(DAY, input, "expected seeds"));
+        };
+        let (_, seeds_str) = seeds_line.split_once(seeds_line.text, "seeds:")?;
+        let seeds = almanac_numbers(&seeds_line, seeds_str)?;
+        if seeds.is_empty() {
+            return Err(seeds_line.error(seeds_str, "expected seed numbers"));
+        }
+        // Part 2 reads the seeds as pairs of a start and a length
+        for pair in seeds.chunks(2) {
+            match *pair {
+                [(_, start), (len_str, len)] if len > 0 => {
+                    check_range_end(&seeds_line, start, len, len_str)?
+                }
+                _ => {
+                    return Err(seeds_line.error(seeds_str, "expected pairs of a seed and a length"))
+                }
+            }
+        }
+        let seeds = seeds.into_iter().map(|(_, seed)| seed).collect();
+        if let Some(line) = blocks[0].get(1) {
+            return Err(line.error(line.text, "expected an empty line"));
+        }
+        let mappings = blocks[1..]
+            .iter()
+            .map(|block| load_mapping(block))
+            .collect::<Result<_, _>>()?;
+        Ok(Almanac { seeds, mappings })
+    }
+
+    fn part1(&self, almanac: &Self::Input) -> i64 {
+        find_nearest_location(almanac.seeds.iter().copied(), &almanac.mappings)
+    }
+
+    fn part2(&self, almanac: &Self::Input) -> NearestLocation {
+        let seed_ranges = almanac
+            .seeds
+            .chunks(2)
+            .map(|pair| pair[0]..pair[0] + pair[1]);
+        let location_ranges = almanac.mappings.iter().fold(
+            seed_ranges.collect(),
+            |ranges: Vec<Range<i64>>, mapping| {
+                ranges
+                    .into_iter()
+                    .flat_map(|range| mapping.map_range(range))
+                    .collect()
+            },
+        );
+        let location = location_ranges
+            .iter()
+            .map(|range| range.start)
+            .min()
+            .unwrap();
+        let mapping = self
+            .show_mapping
+            .then(|| almanac.chain("seed", "location"))
+            .flatten();
+        NearestLocation { location, mapping }
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "show_mapping" => self.show_mapping = parse::setting(name, value)?,
+            _ => return Err(format!("Unknown setting: {name}")),
+        }
+        Ok(())
+    }
+}
+
+pub struct NearestLocation {
+    pub location: i64,
+    mapping: Option<Mapping>,
+}
+
+impl fmt::Display for NearestLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.location)?;
+        if let Some(mapping) = &self.mapping {
+            write!(f, "\n{mapping}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Almanac {
+    pub fn seeds(&self) -> &[i64] {
+        &self.seeds
+    }
+
+    /// The maps in the order of the almanac.
+    pub fn mappings(&self) -> &[Mapping] {
+        &self.mappings
+    }
+
+    /// Looks a map up by its name, like `seed-to-soil`.
+    pub fn mapping(&self, name: &str) -> Option<&Mapping> {
+        self.mappings.iter().find(|mapping| mapping.name() == name)
+    }
+
+    /// Composes the maps leading from the `source` category to the `destination` category, like
+    /// from `seed` to `location`. For the other direction the composed mapping is inverted,
+    /// which only works if it's one-to-one.
+    pub fn chain(&self, source: &str, destination: &str) -> Option<Mapping> {
+        match self.forward_chain(source, destination) {
+            Some(mapping) => Some(mapping),
+            None => self.forward_chain(destination, source)?.inverse(),
+        }
+    }
+
+    fn forward_chain(&self, source: &str, destination: &str) -> Option<Mapping> {
+        let mut composed = Mapping {
+            source: source.to_string(),
+            destination: source.to_string(),
+            ranges: Vec::new(),
+        };
+        // Each map can be used at most once on the way
+        for _ in 0..=self.mappings.len() {
+            if composed.destination == destination {
+                return Some(composed);
+            }
+            let next = self
+                .mappings
+                .iter()
+                .find(|mapping| mapping.source == composed.destination)?;
+            composed = composed.then(next);
+        }
+        None
+    }
+}
+
+/// Parses a map block: its `<source>-to-<destination> map:` header and the ranges.
+fn load_mapping(block: &[Line]) -> Result<Mapping, ParseError> {
+    let header = block[0];
+    let (name, _) = header.split_once(header.text, " map:")?;
+    let (source, destination) = header.split_once(name, "-to-")?;
+    let mut ranges = Vec::new();
+    for line in &block[1..] {
+        let [(_, dest), (_, src), (len_str, len)] = almanac_numbers(line, line.text)?[..] else {
+            return Err(line.error(line.text, "expected three numbers"));
+        };
+        check_range_end(line, src, len, len_str)?;
+        check_range_end(line, dest, len, len_str)?;
+        ranges.push((RangeMap { dest, src, len }, line));
+    }
+    ranges.sort_by_key(|(range_map, _)| range_map.src);
+    if let Some([_, (_, line)]) = ranges
+        .windows(2)
+        .find(|w| w[0].0.src + w[0].0.len > w[1].0.src)
+    {
+        return Err(line.error(line.text, "overlaps another range"));
+    }
+    let ranges = ranges.into_iter().map(|(range_map, _)| range_map).collect();
+    Ok(Mapping {
+        source: source.to_string(),
+        destination: destination.to_string(),
+        ranges,
+    })
+}
+
+/// The numbers of `text` with the text of each, to point at one of them in errors. The almanac
+/// only has non-negative numbers.
+fn almanac_numbers<'a>(line: &Line, text: &'a str) -> Result<Vec<(&'a str, i64)>, ParseError> {
+    text.split_whitespace()
+        .map(|field| match line.number(field)? {
+            number if number < 0 => Err(line.error(field, "expected a non-negative number")),
+            number => Ok((field, number)),
+        })
+        .collect()
+}
+
+/// Checks that the range of `len` numbers from `start` ends within an `i64`, where `len_str` is
+/// the length in `line`.
+fn check_range_end(line: &Line, start: i64, len: i64, len_str: &str) -> Result<(), ParseError> {
+    match start.checked_add(len) {
+        Some(_) => Ok(()),
+        None => Err(line.error(len_str, "expected a range that ends within an i64")),
+    }
+}
+
+fn find_nearest_location(seeds: impl Iterator<Item = i64>, mappings: &[Mapping]) -> i64 {
+    seeds
+        .map(|seed_id| map_seed_to_location(seed_id, mappings))
+        .min()
+        .unwrap()
+}
+
+fn map_seed_to_location(seed_id: i64, mappings: &[Mapping]) -> i64 {
+    let mut id = seed_id;
+    for mapping in mappings {
+        id = mapping.map_src_to_dest(id);
+    }
+    id
+}
+
+/// One map of the almanac, with its ranges sorted by source. Numbers outside of the ranges map
+/// to themselves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mapping {
+    pub source: String,
+    pub destination: String,
+    pub ranges: Vec<RangeMap>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RangeMap {
+    pub dest: i64,
+    pub src: i64,
+    pub len: i64,
+}
+
+impl Mapping {
+    pub fn name(&self) -> String {
+        format!("{}-to-{}", self.source, self.destination)
+    }
+
+    pub fn map_src_to_dest(&self, src: i64) -> i64 {
+        for range_map in &self.ranges {
+            let offset = src - range_map.src;
+            if offset >= 0 && offset < range_map.len {
+                return range_map.dest + offset;
+            }
+        }
+        src
+    }
+
+    /// Maps all numbers of `range`, returning the ranges they are mapped to.
+    pub fn map_range(&self, range: Range<i64>) -> Vec<Range<i64>> {
+        self.split(range)
+            .into_iter()
+            .map(|(part, offset)| part.start + offset..part.end + offset)
+            .collect()
+    }
+
+    /// The mapping that applies this one first and `next` to the result, from this one's source
+    /// to `next`'s destination. Neighboring ranges that shift by the same offset are merged.
+    pub fn then(&self, next: &Mapping) -> Mapping {
+        let mut pieces: Vec<(Range<i64>, i64)> = self
+            .split(0..i64::MAX)
+            .into_iter()
+            .flat_map(|(range, offset)| {
+                let image = range.start + offset..range.end + offset;
+                next.split(image)
+                    .into_iter()
+                    .map(move |(part, next_offset)| {
+                        (part.start - offset..part.end - offset, offset + next_offset)
+                    })
+            })
+            .collect();
+        pieces.sort_by_key(|(range, _)| range.start);
+        let mut ranges: Vec<RangeMap> = Vec::new();
+        for (range, offset) in pieces.into_iter().filter(|&(_, offset)| offset != 0) {
+            match ranges.last_mut() {
+                Some(last)
+                    if last.src + last.len == range.start && last.dest - last.src == offset =>
+                {
+                    last.len += range.end - range.start;
+                }
+                _ => ranges.push(RangeMap {
+                    dest: range.start + offset,
+                    src: range.start,
+                    len: range.end - range.start,
+                }),
+            }
+        }
+        Mapping {
+            source: self.source.clone(),
+            destination: next.destination.clone(),
+            ranges,
+        }
+    }
+
+    /// The mapping back from the destination to the source, if every number has exactly one
+    /// source. That's the case if the ranges are mapped onto the same numbers they cover.
+    pub fn inverse(&self) -> Option<Mapping> {
+        let covered = |ranges: &mut Vec<Range<i64>>| {
+            ranges.sort_by_key(|range| range.start);
+            if ranges.windows(2).any(|w| w[0].end > w[1].start) {
+                return None;
+            }
+            let mut merged: Vec<Range<i64>> = Vec::new();
+            for range in ranges.drain(..) {
+                match merged.last_mut() {
+                    Some(last) if last.end == range.start => last.end = range.end,
+                    _ => merged.push(range),
+                }
+            }
+            Some(merged)
+        };
+        let mut sources: Vec<Range<i64>> =
+            self.ranges.iter().map(|r| r.src..r.src + r.len).collect();
+        let mut images: Vec<Range<i64>> =
+            self.ranges.iter().map(|r| r.dest..r.dest + r.len).collect();
+        if covered(&mut sources)? != covered(&mut images)? {
+            return None;
+        }
+        let mut ranges: Vec<RangeMap> = self
+            .ranges
+            .iter()
+            .map(|r| RangeMap { dest: r.src, src: r.dest, len: r.len })
+            .collect();
+        ranges.sort_by_key(|range_map| range_map.src);
+        Some(Mapping {
+            source: self.destination.clone(),
+            destination: self.source.clone(),
+            ranges,
+        })
+    }
+
+    /// Splits `range` into the parts that are shifted by the same offset, in order.
+    fn split(&self, range: Range<i64>) -> Vec<(Range<i64>, i64)> {
+        let mut parts = Vec::new();
+        let mut start = range.start;
+        for range_map in &self.ranges {
+            let (src_start, src_end) = (range_map.src, range_map.src + range_map.len);
+            if src_end <= start || src_start >= range.end {
+                continue;
+            }
+            if start < src_start {
+                parts.push((start..src_start, 0));
+                start = src_start;
+            }
+            let end = src_end.min(range.end);
+            parts.push((start..end, range_map.dest - range_map.src));
+            start = end;
+        }
+        if start < range.end {
+            parts.push((start..range.end, 0));
+        }
+        parts
+    }
+}
+
+/// Shows the mapping as a piecewise function of the non-negative numbers.
+impl fmt::Display for Mapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.name())?;
+        for (range, offset) in self.split(0..i64::MAX) {
+            let function = match offset {
+                0 => "x".to_string(),
+                1.. => format!("x + {offset}"),
+                _ => format!("x - {}", -offset),
+            };
+            match range.end {
+                i64::MAX => write!(f, "\nx >= {}: {function}", range.start)?,
+                end => write!(f, "\n{} <= x < {end}: {function}", range.start)?,
+            }
+        }
+        Ok(())
+    }
+}
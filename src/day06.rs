@@ -0,0 +1,97 @@
+use crate::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 6;
+
+pub struct Day06;
+
+pub struct Races {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+    /// The time and distance of part 2, with the digits of all races concatenated.
+    kerned: (u64, u64),
+}
+
+impl Solution for Day06 {
+    type Input = Races;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<Line> = parse::lines(DAY, input).collect();
+        let [times_line, distances_line, ..] = lines[..] else {
+            return Err(parse::end_of_input(
+                DAY,
+                input,
+                "expected times and distances",
+            ));
+        };
+        let times = parse_vec(&times_line, "Time:")?;
+        let distances = parse_vec(&distances_line, "Distance:")?;
+        if distances.len() != times.len() {
+            return Err(
+                distances_line.error(distances_line.text, "expected as many distances as times")
+            );
+        }
+        let too_long =
+            |line: &Line| line.error(line.text, "concatenated number doesn't fit into 64 bits");
+        let kerned = (
+            concat_num(&times).ok_or_else(|| too_long(&times_line))?,
+            concat_num(&distances).ok_or_else(|| too_long(&distances_line))?,
+        );
+        Ok(Races { times, distances, kerned })
+    }
+
+    fn part1(&self, Races { times, distances, .. }: &Self::Input) -> u64 {
+        let mut result = 1;
+        for (&race_time, &record_distance) in times.iter().zip(distances.iter()) {
+            result *= num_choices(race_time, record_distance);
+        }
+        result
+    }
+
+    fn part2(&self, races: &Self::Input) -> u64 {
+        let (race_time, record_distance) = races.kerned;
+        num_choices(race_time, record_distance)
+    }
+}
+
+/// The number of ways to charge the boat that beat the record. Charging for `c` milliseconds
+/// travels `c * (race_time - c)`, so the winning charge times lie between the roots of
+/// `c^2 - race_time * c + record_distance`. The integer square root gets close to the lower
+/// root, which is then corrected to the first winning charge time.
+pub fn num_choices(race_time: u64, record_distance: u64) -> u64 {
+    let (time, record) = (race_time as u128, record_distance as u128);
+    let beats_record = |charge: u128| charge * (time - charge) > record;
+    let Some(discriminant) = (time * time).checked_sub(4 * record) else {
+        return 0;
+    };
+    // Up to the middle of the race, charging longer goes further
+    let mut low = (time - discriminant.isqrt()) / 2;
+    while low > 0 && beats_record(low - 1) {
+        low -= 1;
+    }
+    while low <= time / 2 && !beats_record(low) {
+        low += 1;
+    }
+    if low > time / 2 {
+        return 0;
+    }
+    // The winning charge times are symmetric around the middle of the race
+    (time - 2 * low + 1) as u64
+}
+
+fn parse_vec(line: &Line, header: &str) -> Result<Vec<u64>, ParseError> {
+    let numbers_str = line
+        .text
+        .strip_prefix(header)
+        .ok_or_else(|| line.error(line.text, format!("expected {header:?}")))?;
+    line.numbers(numbers_str)
+}
+
+fn concat_num(nums: &[u64]) -> Option<u64> {
+    let digits: String = nums.iter().map(|n| n.to_string()).collect();
+    digits.parse().ok()
+}
@@ -0,0 +1,226 @@
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    parse::{self, ParseError},
+    Fallible, Solution,
+};
+
+const DAY: u8 = 7;
+
+pub struct Day07 {
+    pub part1_rules: Ruleset,
+    pub part2_rules: Ruleset,
+}
+
+impl Default for Day07 {
+    fn default() -> Self {
+        Day07 {
+            part1_rules: Ruleset::camel_cards(),
+            part2_rules: Ruleset::jokers(),
+        }
+    }
+}
+
+/// How hands are ranked. A hand is better if its cards form bigger groups of the same card,
+/// comparing the sizes of the groups from the biggest one. `wildcards` join the biggest group.
+/// Hands with groups of the same sizes are ranked by `tie_break`.
+#[derive(Clone, Debug)]
+pub struct Ruleset {
+    /// All cards, from the lowest to the highest.
+    pub card_order: Vec<u8>,
+    pub wildcards: Vec<u8>,
+    pub hand_size: usize,
+    pub tie_break: TieBreak,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TieBreak {
+    /// Compares the first cards, then the second cards and so on.
+    CardOrder,
+    /// Compares the cards of the biggest groups first, like in poker, then the cards of the
+    /// next group and so on. Wildcards take the card of their group.
+    GroupRank,
+}
+
+impl FromStr for TieBreak {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "card_order" => Ok(TieBreak::CardOrder),
+            "group_rank" => Ok(TieBreak::GroupRank),
+            _ => Err("expected card_order or group_rank".to_string()),
+        }
+    }
+}
+
+impl Ruleset {
+    /// The rules of part 1.
+    pub fn camel_cards() -> Self {
+        Ruleset {
+            card_order: b"23456789TJQKA".to_vec(),
+            wildcards: Vec::new(),
+            hand_size: 5,
+            tie_break: TieBreak::CardOrder,
+        }
+    }
+
+    /// The rules of part 2, where jokers are wild but the lowest card in ties.
+    pub fn jokers() -> Self {
+        Ruleset {
+            card_order: b"J23456789TQKA".to_vec(),
+            wildcards: b"J".to_vec(),
+            ..Self::camel_cards()
+        }
+    }
+
+    /// Changes one of the rules, keeping the old ones if the wildcards aren't among the cards.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let mut rules = self.clone();
+        match name {
+            "card_order" => rules.card_order = value.bytes().collect(),
+            "wildcards" => rules.wildcards = value.bytes().collect(),
+            "hand_size" => rules.hand_size = parse::setting(name, value)?,
+            "tie_break" => rules.tie_break = parse::setting(name, value)?,
+            _ => return Err(format!("Unknown setting: {name}")),
+        }
+        if let Some(&card) = rules
+            .wildcards
+            .iter()
+            .find(|&&card| rules.rank(card).is_none())
+        {
+            return Err(format!(
+                "The wildcard {} isn't in the card order",
+                card as char
+            ));
+        }
+        *self = rules;
+        Ok(())
+    }
+
+    fn rank(&self, card: u8) -> Option<usize> {
+        self.card_order.iter().position(|&c| c == card)
+    }
+}
+
+pub struct Hand {
+    pub cards: Vec<u8>,
+    pub bid: usize,
+}
+
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+    type Answer1 = Fallible<usize>;
+    type Answer2 = Fallible<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(DAY, input)
+            .map(|line| {
+                let (cards_str, bid_str) = line.split_once(line.text, " ")?;
+                for rules in [&self.part1_rules, &self.part2_rules] {
+                    if cards_str.len() != rules.hand_size {
+                        let message = format!("expected {} cards", rules.hand_size);
+                        return Err(line.error(cards_str, message));
+                    }
+                    if let Some(i) = cards_str.bytes().position(|b| rules.rank(b).is_none()) {
+                        let card = cards_str.get(i..i + 1).unwrap_or(cards_str);
+                        return Err(line.error(card, "expected a card"));
+                    }
+                }
+                let bid = line.number(bid_str)?;
+                Ok(Hand { cards: cards_str.bytes().collect(), bid })
+            })
+            .collect()
+    }
+
+    fn part1(&self, hands: &Self::Input) -> Fallible<usize> {
+        score(hands, &self.part1_rules).into()
+    }
+
+    fn part2(&self, hands: &Self::Input) -> Fallible<usize> {
+        score(hands, &self.part2_rules).into()
+    }
+
+    /// The rules can be set as `part1.<rule>` and `part2.<rule>`, like `part2.wildcards=JQ`. The
+    /// hands must suit the rules of both parts.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name.split_once('.') {
+            Some(("part1", rule)) => self.part1_rules.set(rule, value),
+            Some(("part2", rule)) => self.part2_rules.set(rule, value),
+            _ => Err(format!("Unknown setting: {name}")),
+        }
+    }
+}
+
+/// The total winnings: the bid of every hand times its rank among the hands. Fails if a hand has
+/// a card that isn't in the card order.
+pub fn score(hands: &[Hand], rules: &Ruleset) -> Result<usize, String> {
+    let mut ranked: Vec<(HandStrength, usize)> = hands
+        .iter()
+        .map(|hand| {
+            let strength = strength(&hand.cards, rules).ok_or_else(|| {
+                format!(
+                    "The hand {} has a card that isn't in the card order",
+                    String::from_utf8_lossy(&hand.cards)
+                )
+            })?;
+            Ok((strength, hand.bid))
+        })
+        .collect::<Result<_, String>>()?;
+    ranked.sort();
+    let mut result = 0;
+    for (i, (_, bid)) in ranked.into_iter().enumerate() {
+        result += (i + 1) * bid;
+    }
+    Ok(result)
+}
+
+/// The sizes of the groups of the same card, from the biggest one. Five of a kind is `[5]`, a
+/// full house `[3, 2]` and a high card `[1, 1, 1, 1, 1]`, which compare in the same order as
+/// the hand types. Returns `None` if a card isn't in the card order.
+pub fn classify(cards: &[u8], rules: &Ruleset) -> Option<Vec<usize>> {
+    let groups = groups(cards, rules)?;
+    Some(groups.into_iter().map(|(size, _)| size).collect())
+}
+
+/// What hands are sorted by: their type, then the ranks that break ties.
+type HandStrength = (Vec<usize>, Vec<usize>);
+
+fn strength(cards: &[u8], rules: &Ruleset) -> Option<HandStrength> {
+    let groups = groups(cards, rules)?;
+    let tie_break = match rules.tie_break {
+        TieBreak::CardOrder => cards
+            .iter()
+            .map(|&card| rules.rank(card))
+            .collect::<Option<_>>()?,
+        TieBreak::GroupRank => groups.iter().map(|&(_, rank)| rank).collect(),
+    };
+    Some((
+        groups.into_iter().map(|(size, _)| size).collect(),
+        tie_break,
+    ))
+}
+
+/// The groups of the same card as `(size, rank)`, from the biggest and highest one. The
+/// wildcards join the first group, or form a group of the highest card on their own.
+fn groups(cards: &[u8], rules: &Ruleset) -> Option<Vec<(usize, usize)>> {
+    let mut counts = HashMap::<usize, usize>::new();
+    let mut wildcard_count = 0;
+    for &card in cards {
+        if rules.wildcards.contains(&card) {
+            wildcard_count += 1;
+        } else {
+            *counts.entry(rules.rank(card)?).or_default() += 1;
+        }
+    }
+    let mut groups: Vec<(usize, usize)> = counts.into_iter().map(|(rank, n)| (n, rank)).collect();
+    groups.sort_by(|a, b| b.cmp(a));
+    match groups.first_mut() {
+        Some((size, _)) => *size += wildcard_count,
+        None if wildcard_count > 0 => {
+            groups.push((wildcard_count, rules.card_order.len().checked_sub(1)?))
+        }
+        None => {}
+    }
+    Some(groups)
+}
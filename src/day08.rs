@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use regex::Regex;
+
+use crate::{
+    math,
+    parse::{self, Line, ParseError},
+    Fallible, Solution,
+};
+
+const DAY: u8 = 8;
+
+pub struct Day08;
+
+pub struct Network {
+    instructions: Vec<u8>,
+    map: HashMap<String, (String, String)>,
+}
+
+impl Solution for Day08 {
+    type Input = Network;
+    type Answer1 = Fallible<usize>;
+    type Answer2 = Fallible<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let node_re = Regex::new(r"^(\w{3}) = \((\w{3}), (\w{3})\)$").unwrap();
+        let input_lines: Vec<Line> = parse::lines(DAY, input).collect();
+        let Some(instructions_line) = input_lines.first() else {
+            return Err(parse::end_of_input(DAY, input, "expected instructions"));
+        };
+        if let Some(i) = instructions_line.text.find(|ch| ch != 'L' && ch != 'R') {
+            let ch_len = instructions_line.text[i..]
+                .chars()
+                .next()
+                .unwrap()
+                .len_utf8();
+            let offending = &instructions_line.text[i..i + ch_len];
+            return Err(instructions_line.error(offending, "expected L or R"));
+        }
+        let instructions: Vec<u8> = instructions_line.text.bytes().collect();
+        if instructions.is_empty() {
+            return Err(instructions_line.error(instructions_line.text, "expected instructions"));
+        }
+        if let Some(line) = input_lines.get(1).filter(|line| !line.text.is_empty()) {
+            return Err(line.error(line.text, "expected an empty line"));
+        }
+        let mut map = HashMap::<String, (String, String)>::new();
+        let mut destinations = Vec::new();
+        for line in input_lines.iter().skip(2) {
+            let groups = line.captures(&node_re)?;
+            let [node, left, right] = [1, 2, 3].map(|i| groups.get(i).unwrap().as_str());
+            map.insert(node.to_owned(), (left.to_owned(), right.to_owned()));
+            destinations.extend([(line, left), (line, right)]);
+        }
+        if let Some((line, destination)) = destinations
+            .into_iter()
+            .find(|(_, destination)| !map.contains_key(*destination))
+        {
+            return Err(line.error(destination, "expected a known node"));
+        }
+        Ok(Network { instructions, map })
+    }
+
+    fn part1(&self, Network { instructions, map }: &Self::Input) -> Fallible<usize> {
+        let steps = match ["AAA", "ZZZ"]
+            .into_iter()
+            .find(|node| !map.contains_key(*node))
+        {
+            Some(node) => Err(format!("There's no node {node}")),
+            None => path_length("AAA", instructions, map, |node| node == "ZZZ")
+                .ok_or_else(|| "ZZZ can't be reached from AAA".to_string()),
+        };
+        steps.into()
+    }
+
+    fn part2(&self, network: &Self::Input) -> Fallible<usize> {
+        ghosts_meeting(network).into()
+    }
+}
+
+/// The path of a ghost, which always ends up in a cycle: the state after step `offset + length`
+/// is the one after step `offset`. `z_hits` are the steps after which the ghost stands on a
+/// node ending with `Z`, up to the end of the first round of the cycle.
+#[derive(Debug)]
+pub struct GhostPath {
+    pub start: String,
+    pub offset: usize,
+    pub length: usize,
+    pub z_hits: Vec<usize>,
+}
+
+impl GhostPath {
+    /// Follows the ghost from `start` until it's in the same state twice, that is on the same
+    /// node at the same position in the instructions.
+    pub fn find(start: &str, Network { instructions, map }: &Network) -> GhostPath {
+        let mut first_visits = HashMap::<(&str, usize), usize>::new();
+        let mut z_hits = Vec::new();
+        let mut curr = start;
+        for step in 0.. {
+            let state = (curr, step % instructions.len());
+            if let Some(&offset) = first_visits.get(&state) {
+                let length = step - offset;
+                return GhostPath { start: start.to_string(), offset, length, z_hits };
+            }
+            first_visits.insert(state, step);
+            if curr.ends_with('Z') {
+                z_hits.push(step);
+            }
+            let (left, right) = &map[curr];
+            curr = if instructions[state.1] == b'L' {
+                left
+            } else {
+                right
+            };
+        }
+        unreachable!()
+    }
+
+    /// Whether the ghost stands on a `Z` node after `step` steps.
+    pub fn is_z_hit(&self, step: usize) -> bool {
+        if step >= self.offset + self.length {
+            let step = self.offset + (step - self.offset) % self.length;
+            return self.z_hits.contains(&step);
+        }
+        self.z_hits.contains(&step)
+    }
+
+    /// The Z hits that repeat every `length` steps.
+    fn cycle_z_hits(&self) -> impl Iterator<Item = usize> + '_ {
+        self.z_hits
+            .iter()
+            .copied()
+            .filter(|&step| step >= self.offset)
+    }
+}
+
+/// The most combinations of Z hits within the cycles to try before giving up.
+const MAX_COMBINATIONS: usize = 100_000;
+
+/// The first step after which all ghosts starting on a node ending with `A` stand on nodes ending
+/// with `Z`. Before all ghosts are in their cycles, the steps are simply tried out. After that,
+/// every ghost must be at one of its Z hits modulo its cycle length, which is solved with the
+/// Chinese remainder theorem for every combination of Z hits.
+pub fn ghosts_meeting(network: &Network) -> Result<usize, String> {
+    let mut starts: Vec<&String> = network
+        .map
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .collect();
+    starts.sort();
+    if starts.is_empty() {
+        return Err("No node ends with A".to_string());
+    }
+    let paths: Vec<GhostPath> = starts
+        .into_iter()
+        .map(|start| GhostPath::find(start, network))
+        .collect();
+    let all_cycling = paths.iter().map(|path| path.offset).max().unwrap();
+    if let Some(step) = (0..all_cycling).find(|&step| paths.iter().all(|path| path.is_z_hit(step)))
+    {
+        return Ok(step);
+    }
+    if let Some(path) = paths
+        .iter()
+        .find(|path| path.cycle_z_hits().next().is_none())
+    {
+        return Err(format!(
+            "The ghosts never stand on Z nodes at the same time, the one starting at {} stops \
+             reaching them",
+            path.start
+        ));
+    }
+    let mut residues = vec![(0_i128, 1_i128)];
+    for path in &paths {
+        let length = path.length as i128;
+        residues = residues
+            .iter()
+            .flat_map(|&residue| {
+                path.cycle_z_hits()
+                    .filter_map(move |hit| math::crt(residue, (hit as i128 % length, length)))
+            })
+            .collect();
+        residues.sort();
+        residues.dedup();
+        if residues.len() > MAX_COMBINATIONS {
+            return Err(format!(
+                "More than {MAX_COMBINATIONS} combinations of Z hits, the ghosts' cycles are \
+                 too irregular"
+            ));
+        }
+    }
+    let all_cycling = all_cycling as i128;
+    residues
+        .into_iter()
+        .map(|(residue, modulus)| {
+            // The first step at or after all_cycling with this residue
+            let missing = (all_cycling - residue).max(0);
+            residue + (missing + modulus - 1) / modulus * modulus
+        })
+        .min()
+        .ok_or_else(|| "The ghosts never stand on Z nodes at the same time".to_string())?
+        .try_into()
+        .map_err(|_| "The ghosts meet too late to count the steps".to_string())
+}
+
+fn path_length(
+    start: &str,
+    instructions: &[u8],
+    map: &HashMap<String, (String, String)>,
+    stop_fn: impl Fn(&str) -> bool,
+) -> Option<usize> {
+    // After a step for every node at every instruction, the path goes round in circles
+    let max_move_count = map.len() * instructions.len();
+    let mut curr = start;
+    let mut forever_instructions = instructions.iter().cycle();
+    let mut move_count = 0;
+    while !stop_fn(curr) {
+        if move_count == max_move_count {
+            return None;
+        }
+        let instruction = *forever_instructions.next().unwrap();
+        let directions = &map[curr];
+        curr = if instruction == b'L' {
+            &directions.0
+        } else {
+            &directions.1
+        };
+        move_count += 1;
+    }
+    Some(move_count)
+}
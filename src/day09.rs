@@ -0,0 +1,148 @@
+use std::fmt;
+
+use crate::{
+    math,
+    parse::{self, ParseError},
+    Fallible, Solution,
+};
+
+const DAY: u8 = 9;
+
+pub struct Day09 {
+    /// How far past the ends of the histories to extrapolate.
+    pub steps: u64,
+    /// Show the degree of each history's polynomial after the answer of part 1.
+    pub show_degrees: bool,
+}
+
+impl Default for Day09 {
+    fn default() -> Self {
+        Day09 { steps: 1, show_degrees: false }
+    }
+}
+
+/// The values of a history, fitted with the polynomial of the lowest degree through them. The
+/// polynomial is kept as the first value of each row of the difference table, the Newton
+/// forward differences, which give the value at index `k` as the sum of `Δʲ * (k choose j)`.
+pub struct History {
+    pub values: Vec<i64>,
+    /// `None` if the difference table doesn't fit into an `i128`.
+    differences: Option<Vec<i128>>,
+}
+
+impl History {
+    pub fn new(values: Vec<i64>) -> Self {
+        History { differences: forward_differences(&values), values }
+    }
+
+    /// The degree of the polynomial, `0` for a constant history. If the differences don't
+    /// settle to zero within the history, it's one less than the number of values. Returns `None`
+    /// if the difference table doesn't fit into an `i128`.
+    pub fn degree(&self) -> Option<usize> {
+        Some(self.differences.as_ref()?.len().saturating_sub(1))
+    }
+
+    /// The value at `index`, where the history covers the indices from `0`. Returns `None` if
+    /// it or the difference table doesn't fit into an `i128`.
+    pub fn at(&self, index: i128) -> Option<i128> {
+        self.differences
+            .as_ref()?
+            .iter()
+            .enumerate()
+            .try_fold(0i128, |sum, (j, &difference)| {
+                sum.checked_add(difference.checked_mul(math::binomial(index, j)?)?)
+            })
+    }
+
+    /// The value `steps` after the last one.
+    pub fn ahead(&self, steps: u64) -> Option<i128> {
+        self.at(self.values.len() as i128 - 1 + steps as i128)
+    }
+
+    /// The value `steps` before the first one.
+    pub fn behind(&self, steps: u64) -> Option<i128> {
+        self.at(-(steps as i128))
+    }
+}
+
+/// The first value of each row of the difference table, down to the first row of zeros.
+fn forward_differences(values: &[i64]) -> Option<Vec<i128>> {
+    let mut differences = Vec::new();
+    let mut row: Vec<i128> = values.iter().map(|&value| value as i128).collect();
+    while row.iter().any(|&n| n != 0) {
+        differences.push(row[0]);
+        row = row
+            .windows(2)
+            .map(|pair| pair[1].checked_sub(pair[0]))
+            .collect::<Option<_>>()?;
+    }
+    Some(differences)
+}
+
+impl Solution for Day09 {
+    type Input = Vec<History>;
+    type Answer1 = Forecast;
+    type Answer2 = Fallible<i128>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(DAY, input)
+            .map(|line| Ok(History::new(line.numbers(line.text)?)))
+            .collect()
+    }
+
+    fn part1(&self, histories: &Self::Input) -> Forecast {
+        Forecast {
+            sum: extrapolation_sum(histories, |history| history.ahead(self.steps)).into(),
+            degrees: self
+                .show_degrees
+                .then(|| histories.iter().map(History::degree).collect()),
+        }
+    }
+
+    fn part2(&self, histories: &Self::Input) -> Fallible<i128> {
+        extrapolation_sum(histories, |history| history.behind(self.steps)).into()
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "steps" => self.steps = parse::setting(name, value)?,
+            "show_degrees" => self.show_degrees = parse::setting(name, value)?,
+            _ => return Err(format!("Unknown setting: {name}")),
+        }
+        Ok(())
+    }
+}
+
+pub struct Forecast {
+    pub sum: Fallible<i128>,
+    /// The degree of each history, if they are shown.
+    degrees: Option<Vec<Option<usize>>>,
+}
+
+impl fmt::Display for Forecast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.sum)?;
+        for (i, degree) in self.degrees.iter().flatten().enumerate() {
+            match degree {
+                Some(degree) => write!(f, "\nLine {}: degree {degree}", i + 1)?,
+                None => write!(f, "\nLine {}: differences don't fit into an i128", i + 1)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn extrapolation_sum(
+    histories: &[History],
+    value: impl Fn(&History) -> Option<i128>,
+) -> Result<i128, String> {
+    let mut sum: i128 = 0;
+    for (i, history) in histories.iter().enumerate() {
+        let value = value(history)
+            .ok_or_else(|| format!("The value of line {} doesn't fit into an i128", i + 1))?;
+        sum = sum
+            .checked_add(value)
+            .ok_or_else(|| "The sum doesn't fit into an i128".to_string())?;
+    }
+    Ok(sum)
+}
@@ -0,0 +1,251 @@
+use std::str::FromStr;
+
+use crate::{
+    direction::Direction::{self, *},
+    grid::{Grid, Pos},
+    parse::{self, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 10;
+
+#[derive(Default)]
+pub struct Day10 {
+    pub inside_method: InsideMethod,
+}
+
+/// How part 2 finds the tiles inside the loop.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum InsideMethod {
+    /// Walks along the loop and marks the tiles on its inner side, up to the next loop tile.
+    #[default]
+    Sides,
+    /// Scans every row and counts how often it crosses the loop.
+    Crossings,
+}
+
+impl FromStr for InsideMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "sides" => Ok(InsideMethod::Sides),
+            "crossings" => Ok(InsideMethod::Crossings),
+            _ => Err("expected sides or crossings".to_string()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Tile {
+    symbol: u8,
+    on_path: bool,
+}
+
+impl From<u8> for Tile {
+    fn from(symbol: u8) -> Self {
+        Tile { symbol, on_path: false }
+    }
+}
+
+/// The maze with the tiles of the loop through the start tile marked, and `S` replaced with the
+/// pipe it stands for. `path` is the loop from the start tile, with the direction each tile is
+/// left in.
+pub struct Maze {
+    tiles: Grid<Tile>,
+    path: Vec<(Pos, Direction)>,
+}
+
+impl Solution for Day10 {
+    type Input = Maze;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let tiles = parse::grid(DAY, input, b"|-LJ7F.S")?.map(|&b| Tile::from(b));
+        let (y, x) = find_start(&tiles).ok_or_else(|| {
+            let first_line = parse::lines(DAY, input).next().unwrap();
+            first_line.error(first_line.text, "expected a start tile \"S\"")
+        })?;
+        trace_loop(tiles, (y, x)).ok_or_else(|| {
+            let line = parse::lines(DAY, input).nth(y).unwrap();
+            line.error(&line.text[x..x + 1], "expected the start of a loop")
+        })
+    }
+
+    fn part1(&self, maze: &Self::Input) -> usize {
+        maze.path.len() / 2
+    }
+
+    fn part2(&self, maze: &Self::Input) -> usize {
+        match self.inside_method {
+            InsideMethod::Sides => count_inside_by_sides(maze),
+            InsideMethod::Crossings => count_inside_by_crossings(maze),
+        }
+    }
+
+    /// `inside_method` is `sides` or `crossings`.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "inside_method" => self.inside_method = parse::setting(name, value)?,
+            _ => return Err(format!("Unknown setting: {name}")),
+        }
+        Ok(())
+    }
+}
+
+/// The inner side of the loop is on the left if it turns left more often than right. From
+/// every loop tile, the tiles on that side of the way in and the way out are inside, up to the
+/// next loop tile.
+fn count_inside_by_sides(Maze { tiles, path }: &Maze) -> usize {
+    let incoming = |i: usize| path[(i + path.len() - 1) % path.len()].1;
+    let net_rotation: isize = (0..path.len())
+        .map(|i| match path[i].1 {
+            outgoing if outgoing == incoming(i).rotate_ccw() => 1,
+            outgoing if outgoing == incoming(i).rotate_cw() => -1,
+            _ => 0,
+        })
+        .sum();
+    let inner_side = |direction: Direction| match net_rotation > 0 {
+        true => direction.rotate_ccw(),
+        false => direction.rotate_cw(),
+    };
+    let mut inside = tiles.map(|_| false);
+    for (i, &(pos, outgoing)) in path.iter().enumerate() {
+        for side in [inner_side(incoming(i)), inner_side(outgoing)] {
+            let mut inner_pos = pos;
+            while let Some(next_pos) = tiles.offset(inner_pos, side.delta()) {
+                if tiles[next_pos].on_path {
+                    break;
+                }
+                inside[next_pos] = true;
+                inner_pos = next_pos;
+            }
+        }
+    }
+    inside.cells().filter(|&&inside| inside).count()
+}
+
+fn count_inside_by_crossings(maze: &Maze) -> usize {
+    let mut inner_tile_count = 0;
+    for row in maze.tiles.rows() {
+        let mut inside = false;
+        let mut expecting_symbol = 0;
+        for tile in row {
+            let symbol = tile.symbol;
+            if !tile.on_path {
+                if inside {
+                    inner_tile_count += 1;
+                }
+            } else {
+                match symbol {
+                    b'|' => {
+                        inside = !inside;
+                    }
+                    b'-' => {}
+                    b'F' => {
+                        expecting_symbol = b'J';
+                    }
+                    b'L' => {
+                        expecting_symbol = b'7';
+                    }
+                    b'7' | b'J' => {
+                        if expecting_symbol == symbol {
+                            inside = !inside;
+                        }
+                        expecting_symbol = 0;
+                    }
+                    _ => panic!("{}", symbol as char),
+                }
+            }
+        }
+    }
+    inner_tile_count
+}
+
+/// Marks the tiles on the loop through the start tile, replacing `S` with the pipe it stands
+/// for. Returns `None` if the start tile isn't on a loop.
+fn trace_loop(tiles: Grid<Tile>, start: Pos) -> Option<Maze> {
+    let options = start_tile_options(&tiles, start);
+    // Any two of the pipes connecting to the start tile can be the loop's ends
+    options.iter().find_map(|&first_direction| {
+        let mut tiles = tiles.clone();
+        tiles[start].on_path = true;
+        let mut pos = start;
+        let mut direction = first_direction;
+        let mut path = vec![(start, first_direction)];
+        loop {
+            pos = tiles.offset(pos, direction.delta())?;
+            if pos == start {
+                let last_direction = direction.opposite();
+                if last_direction == first_direction || !options.contains(&last_direction) {
+                    return None;
+                }
+                tiles[start].symbol = tile_symbol(first_direction, last_direction);
+                return Some(Maze { tiles, path });
+            }
+            let tile = &mut tiles[pos];
+            if tile.on_path {
+                return None;
+            }
+            tile.on_path = true;
+            direction = new_direction(tile.symbol, direction)?;
+            path.push((pos, direction));
+        }
+    })
+}
+
+/// The direction out of a pipe entered going `direction`, if the pipe connects that way.
+fn new_direction(symbol: u8, direction: Direction) -> Option<Direction> {
+    match (symbol, direction) {
+        (b'|', North) => Some(North),
+        (b'|', South) => Some(South),
+        (b'-', West) => Some(West),
+        (b'-', East) => Some(East),
+        (b'7', North) => Some(West),
+        (b'7', East) => Some(South),
+        (b'F', North) => Some(East),
+        (b'F', West) => Some(South),
+        (b'J', South) => Some(West),
+        (b'J', East) => Some(North),
+        (b'L', South) => Some(East),
+        (b'L', West) => Some(North),
+        _ => None,
+    }
+}
+
+fn find_start(maze: &Grid<Tile>) -> Option<Pos> {
+    maze.iter()
+        .find(|(_, tile)| tile.symbol == b'S')
+        .map(|(pos, _)| pos)
+}
+
+/// The pipe connecting two different directions.
+fn tile_symbol(a: Direction, b: Direction) -> u8 {
+    match (a, b) {
+        (North, South) | (South, North) => b'|',
+        (North, West) | (West, North) => b'J',
+        (North, East) | (East, North) => b'L',
+        (South, West) | (West, South) => b'7',
+        (South, East) | (East, South) => b'F',
+        _ => b'-',
+    }
+}
+
+/// The directions from the start tile to the pipes connecting to it.
+fn start_tile_options(maze: &Grid<Tile>, start: Pos) -> Vec<Direction> {
+    let connecting_symbols: [(Direction, &[u8]); 4] = [
+        (North, b"|7F"),
+        (South, b"|JL"),
+        (West, b"-FL"),
+        (East, b"-J7"),
+    ];
+    connecting_symbols
+        .into_iter()
+        .filter(|(direction, symbols)| {
+            maze.offset(start, direction.delta())
+                .is_some_and(|pos| symbols.contains(&maze[pos].symbol))
+        })
+        .map(|(direction, _)| direction)
+        .collect()
+}
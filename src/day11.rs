@@ -0,0 +1,97 @@
+use std::ops::Range;
+
+use crate::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 11;
+
+pub struct Day11;
+
+#[derive(Copy, Clone)]
+enum Weight {
+    Normal,
+    Expanded,
+}
+use Weight::*;
+
+pub struct Universe {
+    galaxy_locations: Vec<(usize, usize)>,
+    row_weights: Vec<Weight>,
+    col_weights: Vec<Weight>,
+}
+
+impl Solution for Day11 {
+    type Input = Universe;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let universe = parse::grid(DAY, input, b".#")?;
+
+        let row_weights: Vec<Weight> = universe.rows().map(|row| weight(row.iter())).collect();
+        let col_weights: Vec<Weight> = universe.columns().map(weight).collect();
+
+        let galaxy_locations = universe
+            .iter()
+            .filter(|&(_, &b)| b == b'#')
+            .map(|((y, x), _)| (x, y))
+            .collect::<Vec<_>>();
+
+        Ok(Universe { galaxy_locations, row_weights, col_weights })
+    }
+
+    fn part1(&self, universe: &Self::Input) -> usize {
+        distance_sum(2, universe)
+    }
+
+    fn part2(&self, universe: &Self::Input) -> usize {
+        distance_sum(1_000_000, universe)
+    }
+}
+
+fn weight<'a>(mut line: impl Iterator<Item = &'a u8>) -> Weight {
+    if line.all(|&b| b == b'.') {
+        Expanded
+    } else {
+        Normal
+    }
+}
+
+fn distance_sum(expansion_factor: usize, universe: &Universe) -> usize {
+    let Universe { galaxy_locations, row_weights, col_weights } = universe;
+    galaxy_locations
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &(x1, y1))| {
+            galaxy_locations.iter().skip(i + 1).map(move |&(x2, y2)| {
+                distance_1d(row_weights, expansion_factor, y1, y2)
+                    + distance_1d(col_weights, expansion_factor, x1, x2)
+            })
+        })
+        .sum()
+}
+
+fn distance_1d(
+    data: &[Weight],
+    expansion_factor: usize,
+    start: usize,
+    destination: usize,
+) -> usize {
+    data[to_range(start, destination)]
+        .iter()
+        .map(|w| match w {
+            Normal => 1,
+            Expanded => expansion_factor,
+        })
+        .sum()
+}
+
+fn to_range(n1: usize, n2: usize) -> Range<usize> {
+    if n1 <= n2 {
+        n1..n2
+    } else {
+        n2..n1
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use crate::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 12;
+
+type Count = usize;
+type AsciiChar = u8;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<(Vec<AsciiChar>, Vec<Count>)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(DAY, input)
+            .map(|line| {
+                let (record, groups) = line.split_once(line.text, " ")?;
+                if let Some(i) = record.find(|ch| !".#?".contains(ch)) {
+                    return Err(line.error(&record[i..], "expected one of \".#?\""));
+                }
+                let group_sizes = groups
+                    .split(",")
+                    .map(|s| line.number(s))
+                    .collect::<Result<Vec<Count>, _>>()?;
+                Ok((record.as_bytes().to_vec(), group_sizes))
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> usize {
+        input
+            .iter()
+            .map(|(record, group_sizes)| {
+                num_group_placements(record, group_sizes, &mut HashMap::new())
+            })
+            .sum()
+    }
+
+    fn part2(&self, input: &Self::Input) -> usize {
+        fn unfold_string(input: &[AsciiChar]) -> Vec<AsciiChar> {
+            [input, input, input, input, input].join(&b'?')
+        }
+
+        fn unfold_num(input: &[Count]) -> Vec<Count> {
+            [input, input, input, input, input].concat().to_vec()
+        }
+
+        input
+            .iter()
+            .map(|(record, group_sizes)| {
+                num_group_placements(
+                    &unfold_string(record),
+                    &unfold_num(group_sizes),
+                    &mut HashMap::new(),
+                )
+            })
+            .sum()
+    }
+}
+
+/// Counts the ways to place the groups into the record. Recursive calls only ever see suffixes
+/// of the original record and group list, so their lengths identify them in `memo`.
+fn num_group_placements(
+    record: &[AsciiChar],
+    group_sizes: &[Count],
+    memo: &mut HashMap<(usize, usize), usize>,
+) -> usize {
+    let memo_key = (record.len(), group_sizes.len());
+    if let Some(&count) = memo.get(&memo_key) {
+        return count;
+    }
+    let group_size = group_sizes[0];
+    let mut count = 0;
+    let min_leftover_size =
+        group_size + group_sizes[1..].iter().map(|size| size + 1).sum::<usize>();
+    let Some(last_start) = record.len().checked_sub(min_leftover_size) else {
+        return 0;
+    };
+    let mut hashtag_encountered = false;
+    'outer: for i in 0..=last_start {
+        if hashtag_encountered {
+            break;
+        }
+        hashtag_encountered = record[i] == b'#';
+        for j in 0..group_size {
+            if record[i + j] == b'.' {
+                continue 'outer;
+            }
+        }
+        if i + group_size < record.len() && record[i + group_size] == b'#' {
+            continue;
+        }
+        let tail_groups = &group_sizes[1..];
+        let tail_index = i + group_size + 1;
+        let tail_record = if tail_index <= record.len() {
+            &record[tail_index..]
+        } else {
+            &record[0..0]
+        };
+        if tail_groups.is_empty() {
+            if !tail_record.contains(&b'#') {
+                count += 1;
+            }
+            continue;
+        }
+        if !tail_record.is_empty() {
+            count += num_group_placements(tail_record, tail_groups, memo);
+        }
+    }
+    memo.insert(memo_key, count);
+    count
+}
@@ -0,0 +1,78 @@
+use std::cmp::min;
+
+use crate::{
+    grid::Grid,
+    parse::{self, ParseError},
+    Fallible, Solution,
+};
+
+const DAY: u8 = 13;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Grid<u8>>;
+    type Answer1 = Fallible<usize>;
+    type Answer2 = Fallible<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::blocks(DAY, input)
+            .iter()
+            .map(|block| parse::grid_rows(block, b".#"))
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Fallible<usize> {
+        reflection_sum(input, 0).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Fallible<usize> {
+        reflection_sum(input, 1).into()
+    }
+}
+
+fn reflection_sum(images: &[Grid<u8>], required_smudge_count: usize) -> Result<usize, String> {
+    images
+        .iter()
+        .enumerate()
+        .map(|(i, image)| {
+            find_reflection(image, required_smudge_count).ok_or_else(|| {
+                format!(
+                    "Pattern {} has no reflection with a smudge count of {required_smudge_count}",
+                    i + 1
+                )
+            })
+        })
+        .sum()
+}
+
+fn find_reflection(image: &Grid<u8>, required_smudge_count: usize) -> Option<usize> {
+    if let Some(row) = find_horizontal_reflection(image, required_smudge_count) {
+        return Some(100 * row);
+    }
+    find_horizontal_reflection(&image.transpose(), required_smudge_count)
+}
+
+/// Finds the number of rows above a horizontal mirror line, where the reflection differs from
+/// the image in exactly `required_smudge_count` places.
+fn find_horizontal_reflection(image: &Grid<u8>, required_smudge_count: usize) -> Option<usize> {
+    'outer: for i in 1..image.height() {
+        let mut smudge_count = 0;
+        for j in 0..min(i, image.height() - i) {
+            let upper_row = image.row(i - j - 1);
+            let lower_row = image.row(i + j);
+            for (upper, lower) in upper_row.iter().zip(lower_row) {
+                if upper != lower {
+                    if smudge_count == required_smudge_count {
+                        continue 'outer;
+                    }
+                    smudge_count += 1;
+                }
+            }
+        }
+        if smudge_count == required_smudge_count {
+            return Some(i);
+        }
+    }
+    None
+}
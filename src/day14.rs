@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use crate::{
+    grid::Grid,
+    parse::{self, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 14;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::grid(DAY, input, b"O#.")
+    }
+
+    fn part1(&self, platform: &Self::Input) -> usize {
+        north_beam_load(&roll_north(platform.clone()))
+    }
+
+    fn part2(&self, platform: &Self::Input) -> usize {
+        let billionth = platform_after_n_cycles(1_000_000_000, platform.clone());
+        north_beam_load(&billionth)
+    }
+}
+
+fn platform_after_n_cycles(n: usize, mut platform: Grid<u8>) -> Grid<u8> {
+    let mut platforms: HashMap<Grid<u8>, usize> = HashMap::new();
+    for i in 1..=n {
+        platform = run_cycle(platform);
+        if let Some(&prev_i) = platforms.get(&platform) {
+            let period_length = i - prev_i;
+            return platforms
+                .into_iter()
+                .find(|&(_, matching_i)| matching_i == (prev_i + (n - prev_i) % period_length))
+                .unwrap()
+                .0;
+        }
+        platforms.insert(platform.clone(), i);
+    }
+    platform
+}
+
+/// Rolls the rocks north, west, south and east. Rotating the platform clockwise after rolling
+/// north brings the next direction to the north.
+fn run_cycle(mut platform: Grid<u8>) -> Grid<u8> {
+    for _ in 0..4 {
+        platform = roll_north(platform).rotate_cw();
+    }
+    platform
+}
+
+fn roll_north(mut platform: Grid<u8>) -> Grid<u8> {
+    for x in 0..platform.width() {
+        let mut available_y = 0;
+        for y in 0..platform.height() {
+            match platform[(y, x)] {
+                b'O' => {
+                    if y != available_y {
+                        platform[(available_y, x)] = b'O';
+                        platform[(y, x)] = b'.';
+                    }
+                    available_y += 1;
+                }
+                b'#' => {
+                    available_y = y + 1;
+                }
+                _ => {}
+            }
+        }
+    }
+    platform
+}
+
+fn north_beam_load(platform: &Grid<u8>) -> usize {
+    platform
+        .iter()
+        .filter(|&(_, &b)| b == b'O')
+        .map(|((y, _), _)| platform.height() - y)
+        .sum()
+}
@@ -0,0 +1,125 @@
+use std::{array, sync::OnceLock};
+
+use regex::Regex;
+
+use crate::{
+    parse::{self, Line, ParseError},
+    Fallible, Solution,
+};
+
+const DAY: u8 = 15;
+
+type Lens<'a> = (&'a [u8], u8);
+type BoxArray<'a> = [[Lens<'a>; 6]; 256];
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Vec<u8>>;
+    type Answer1 = usize;
+    type Answer2 = Fallible<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<Line> = parse::lines(DAY, input).collect();
+        let [line] = lines[..] else {
+            return match lines.get(1) {
+                Some(extra) => Err(extra.error(extra.text, "expected a single line")),
+                None => Err(parse::end_of_input(DAY, input, "expected steps")),
+            };
+        };
+        line.text
+            .split(",")
+            .map(|step| match step_re().is_match(step) {
+                true => Ok(step.as_bytes().to_vec()),
+                false => Err(line.error(step, "expected \"label-\" or \"label=power\"")),
+            })
+            .collect()
+    }
+
+    fn part1(&self, init_sequence: &Self::Input) -> usize {
+        init_sequence
+            .iter()
+            .map(|step| hash(step) as usize)
+            .sum::<usize>()
+    }
+
+    fn part2(&self, init_sequence: &Self::Input) -> Fallible<usize> {
+        focusing_power(init_sequence).into()
+    }
+}
+
+fn focusing_power(init_sequence: &[Vec<u8>]) -> Result<usize, String> {
+    let mut box_array: BoxArray = array::from_fn(|_| Default::default());
+    for step in init_sequence {
+        if let Some(index_of_dash) = step.iter().position(|&b| b == b'-') {
+            let label = &step[0..index_of_dash];
+            remove(&mut box_array, label);
+        } else if let Some(index_of_eq) = step.iter().position(|&b| b == b'=') {
+            let label = &step[0..index_of_eq];
+            let power = step[index_of_eq + 1] - b'0';
+            insert(&mut box_array, label, power)?;
+        } else {
+            panic!("Invalid step");
+        }
+    }
+    let mut result = 0;
+    for (i, lens_box) in box_array.into_iter().enumerate() {
+        for (j, (_, power)) in lens_box.into_iter().enumerate() {
+            result += (i + 1) * (j + 1) * power as usize;
+        }
+    }
+    Ok(result)
+}
+
+fn step_re() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"^[^-=,]+(-|=[1-9])$").unwrap())
+}
+
+fn hash(bytes: &[u8]) -> u8 {
+    bytes
+        .iter()
+        .fold(0, |hash, b| hash.wrapping_add(*b).wrapping_mul(17))
+}
+
+fn insert<'a, 'b: 'a>(
+    box_array: &mut BoxArray<'a>,
+    label: &'b [u8],
+    power: u8,
+) -> Result<(), String> {
+    let hash = hash(label);
+    let lens_box = &mut box_array[hash as usize];
+    if let Some((_, stored_power)) = lens_box
+        .iter_mut()
+        .find(|(stored_label, _)| *stored_label == label)
+    {
+        *stored_power = power;
+    } else {
+        let mut inserted = false;
+        for lens in lens_box.iter_mut() {
+            if lens.0.is_empty() {
+                *lens = (label, power);
+                inserted = true;
+                break;
+            }
+        }
+        if !inserted {
+            return Err(format!("Box {hash} can't hold more than 6 lenses"));
+        }
+    }
+    Ok(())
+}
+
+fn remove(box_array: &mut BoxArray, label: &[u8]) {
+    let hash = hash(label);
+    let lens_box = &mut box_array[hash as usize];
+    if let Some(pos) = lens_box
+        .iter()
+        .position(|&(label_in_box, _)| label_in_box == label)
+    {
+        for i in pos..lens_box.len() - 1 {
+            lens_box[i] = lens_box[i + 1];
+        }
+        lens_box[lens_box.len() - 1] = (&[], 0);
+    }
+}
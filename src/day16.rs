@@ -0,0 +1,125 @@
+use crate::{
+    grid::Grid,
+    parse::{self, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 16;
+
+pub struct Day16;
+
+#[derive(Default, Clone, Copy)]
+pub struct Tile {
+    symbol: u8,
+    visited_directions: [bool; 4],
+}
+
+#[derive(Clone, Copy)]
+struct Photon {
+    y: usize,
+    x: usize,
+    dir_y: isize,
+    dir_x: isize,
+}
+
+impl Solution for Day16 {
+    type Input = Grid<Tile>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse::grid(DAY, input, b".|-/\\")?.map(|&b| Tile::from(b)))
+    }
+
+    fn part1(&self, grid: &Self::Input) -> usize {
+        let mut grid = grid.clone();
+        count_energized_tiles(&mut grid, Photon { y: 0, x: 0, dir_y: 0, dir_x: 1 })
+    }
+
+    fn part2(&self, grid: &Self::Input) -> usize {
+        let mut grid = grid.clone();
+        let (height, width) = (grid.height(), grid.width());
+        let from_sides = (0..height).flat_map(|y| {
+            [
+                Photon { y, x: 0, dir_y: 0, dir_x: 1 },
+                Photon { y, x: width - 1, dir_y: 0, dir_x: -1 },
+            ]
+        });
+        let from_top_and_bottom = (0..width).flat_map(|x| {
+            [
+                Photon { y: 0, x, dir_y: 1, dir_x: 0 },
+                Photon { y: height - 1, x, dir_y: -1, dir_x: 0 },
+            ]
+        });
+        from_sides
+            .chain(from_top_and_bottom)
+            .map(|photon| count_energized_tiles(&mut grid, photon))
+            .max()
+            .unwrap()
+    }
+}
+
+fn count_energized_tiles(grid: &mut Grid<Tile>, init_state: Photon) -> usize {
+    let mut photons: Vec<Photon> = vec![init_state];
+    while !photons.is_empty() {
+        let mut i = 0;
+        while i < photons.len() {
+            let Photon { y, x, mut dir_y, mut dir_x } = photons[i];
+            let tile = &mut grid[(y, x)];
+            let did_visit = tile.did_visit_direction_mut(dir_y, dir_x);
+            if *did_visit {
+                photons.remove(i);
+                break;
+            }
+            *did_visit = true;
+            match tile.symbol {
+                b'\\' => (dir_y, dir_x) = (dir_x, dir_y),
+                b'/' => (dir_y, dir_x) = (-dir_x, -dir_y),
+                b'-' => {
+                    if dir_x == 0 {
+                        photons.push(Photon { y, x, dir_y: dir_x, dir_x: dir_y });
+                        (dir_y, dir_x) = (-dir_x, -dir_y)
+                    }
+                }
+                b'|' => {
+                    if dir_y == 0 {
+                        photons.push(Photon { y, x, dir_y: dir_x, dir_x: dir_y });
+                        (dir_y, dir_x) = (-dir_x, -dir_y)
+                    }
+                }
+                b'.' => {}
+                _ => panic!("Invalid tile symbol"),
+            }
+            if let Some((y, x)) = grid.offset((y, x), (dir_y, dir_x)) {
+                photons[i] = Photon { y, x, dir_y, dir_x };
+                i += 1;
+            } else {
+                photons.remove(i);
+            }
+        }
+    }
+    let mut energized_count = 0;
+    for tile in grid.cells_mut() {
+        if tile.visited_directions != [false, false, false, false] {
+            energized_count += 1;
+            tile.visited_directions = [false, false, false, false];
+        }
+    }
+    energized_count
+}
+
+impl Tile {
+    fn did_visit_direction_mut<'a, 'b: 'a>(
+        &'b mut self,
+        dir_y: isize,
+        dir_x: isize,
+    ) -> &'a mut bool {
+        &mut self.visited_directions[(2 * ((dir_y + 1) / 2) + dir_x + 1) as usize]
+    }
+}
+
+impl From<u8> for Tile {
+    fn from(symbol: u8) -> Self {
+        Tile { symbol, ..Default::default() }
+    }
+}
@@ -0,0 +1,228 @@
+use std::fmt;
+
+use crate::{
+    direction::{
+        Direction,
+        Turn::{self, *},
+    },
+    grid::{Grid, Pos},
+    parse::{self, ParseError},
+    search, Fallible, Solution,
+};
+
+const DAY: u8 = 17;
+
+pub struct Day17 {
+    pub part1_rules: CrucibleRules,
+    pub part2_rules: CrucibleRules,
+    /// Show the city with the route of the crucible after the answers.
+    pub show_route: bool,
+}
+
+impl Default for Day17 {
+    fn default() -> Self {
+        Day17 {
+            part1_rules: CrucibleRules::CRUCIBLE,
+            part2_rules: CrucibleRules::ULTRA_CRUCIBLE,
+            show_route: false,
+        }
+    }
+}
+
+/// How a crucible may move. It has to move `min_straight` blocks in one direction before it can
+/// turn or stop, and can't move more than `max_straight` blocks in one direction. Turning costs
+/// `turn_cost` on top of the heat loss of the block it turns into.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CrucibleRules {
+    pub min_straight: u16,
+    pub max_straight: u16,
+    pub allow_reverse: bool,
+    pub turn_cost: u32,
+}
+
+impl CrucibleRules {
+    pub const CRUCIBLE: CrucibleRules = CrucibleRules {
+        min_straight: 1,
+        max_straight: 3,
+        allow_reverse: false,
+        turn_cost: 0,
+    };
+    pub const ULTRA_CRUCIBLE: CrucibleRules = CrucibleRules {
+        min_straight: 4,
+        max_straight: 10,
+        allow_reverse: false,
+        turn_cost: 0,
+    };
+
+    fn allows(&self, turn: Turn, straight_steps: u16) -> bool {
+        match turn {
+            Straight => straight_steps < self.max_straight,
+            Back if !self.allow_reverse => false,
+            _ => straight_steps >= self.min_straight,
+        }
+    }
+
+    /// Changes one of the rules, keeping the old ones if the new ones are impossible.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let mut rules = *self;
+        match name {
+            "min_straight" => rules.min_straight = parse::setting(name, value)?,
+            "max_straight" => rules.max_straight = parse::setting(name, value)?,
+            "allow_reverse" => rules.allow_reverse = parse::setting(name, value)?,
+            "turn_cost" => rules.turn_cost = parse::setting(name, value)?,
+            _ => return Err(format!("Unknown setting: {name}")),
+        }
+        if rules.max_straight == 0 {
+            return Err("max_straight must be at least 1".to_string());
+        }
+        if rules.min_straight > rules.max_straight {
+            return Err(format!(
+                "min_straight ({}) must not be above max_straight ({})",
+                rules.min_straight, rules.max_straight
+            ));
+        }
+        *self = rules;
+        Ok(())
+    }
+}
+
+/// The state of the crucible after it entered the block at `(y, x)`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DijkstraNode {
+    pub y: u16,
+    pub x: u16,
+    pub direction: Option<Direction>,
+    pub straight_steps: u16,
+}
+
+impl DijkstraNode {
+    fn pos(&self) -> Pos {
+        (self.y as usize, self.x as usize)
+    }
+}
+
+pub struct Route {
+    pub heat_loss: u32,
+    pub nodes: Vec<DijkstraNode>,
+    rendering: Option<Grid<u8>>,
+}
+
+impl fmt::Display for Route {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.heat_loss)?;
+        if let Some(rendering) = &self.rendering {
+            write!(f, "\n{}", rendering.to_string().trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day17 {
+    type Input = Grid<u8>;
+    type Answer1 = Fallible<Route>;
+    type Answer2 = Fallible<Route>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse::grid(DAY, input, b"0123456789")?.map(|b| b - b'0'))
+    }
+
+    fn part1(&self, city: &Self::Input) -> Fallible<Route> {
+        self.route(city, &self.part1_rules)
+    }
+
+    fn part2(&self, city: &Self::Input) -> Fallible<Route> {
+        self.route(city, &self.part2_rules)
+    }
+
+    /// Besides `show_route`, the crucible rules can be set as `part1.<rule>` and `part2.<rule>`,
+    /// like `part2.max_straight`. The rules are checked after every setting, so raising
+    /// `min_straight` above the current `max_straight` needs `max_straight` to be set first.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name.split_once('.') {
+            Some(("part1", rule)) => self.part1_rules.set(rule, value),
+            Some(("part2", rule)) => self.part2_rules.set(rule, value),
+            _ if name == "show_route" => {
+                self.show_route = parse::setting(name, value)?;
+                Ok(())
+            }
+            _ => Err(format!("Unknown setting: {name}")),
+        }
+    }
+}
+
+impl Day17 {
+    fn route(&self, city: &Grid<u8>, rules: &CrucibleRules) -> Fallible<Route> {
+        let Some((heat_loss, nodes)) = least_heat_loss(city, rules) else {
+            return Err("The crucible can't reach the factory".to_string()).into();
+        };
+        let rendering = self.show_route.then(|| render_route(city, &nodes));
+        Ok(Route { heat_loss, nodes, rendering }).into()
+    }
+}
+
+/// Returns the least heat loss on the way to the factory and the route that achieves it, or
+/// `None` if the rules don't let the crucible get there. If the city is a single block, the
+/// crucible is already at the factory.
+pub fn least_heat_loss(city: &Grid<u8>, rules: &CrucibleRules) -> Option<(u32, Vec<DijkstraNode>)> {
+    let factory = (city.height() - 1, city.width() - 1);
+    let start = DijkstraNode { y: 0, x: 0, direction: None, straight_steps: 0 };
+    let paths = search::dijkstra(
+        [start],
+        |&node| neighbors(city, node, rules),
+        |node| {
+            node.pos() == factory
+                && (node.direction.is_none() || node.straight_steps >= rules.min_straight)
+        },
+    );
+    let goal = paths.goal()?;
+    Some((paths.distance(goal)?, paths.path_to(goal)?))
+}
+
+/// Draws the city like the puzzle text does, with the heat loss of every block and an arrow
+/// where the route enters a block.
+pub fn render_route(city: &Grid<u8>, route: &[DijkstraNode]) -> Grid<u8> {
+    let mut rendering = city.map(|&heat_loss| b'0' + heat_loss);
+    for node in route {
+        if let Some(direction) = node.direction {
+            rendering[node.pos()] = direction.to_arrow();
+        }
+    }
+    rendering
+}
+
+/// The states the crucible can move to from `node`, with the heat lost on the way.
+fn neighbors(
+    city: &Grid<u8>,
+    node: DijkstraNode,
+    rules: &CrucibleRules,
+) -> Vec<(DijkstraNode, u32)> {
+    let DijkstraNode { y, x, direction, straight_steps } = node;
+    let moves: Vec<(Direction, Turn)> = match direction {
+        Some(incoming_direction) => [Straight, Left, Right, Back]
+            .into_iter()
+            .filter(|&turn| rules.allows(turn, straight_steps))
+            .map(|turn| (incoming_direction.after_turn(turn), turn))
+            .collect(),
+        // Standing at the start, the crucible can set off in any direction
+        None => Direction::ALL
+            .map(|direction| (direction, Straight))
+            .to_vec(),
+    };
+    moves
+        .into_iter()
+        .filter_map(|(outgoing_direction, turn)| {
+            let (y, x) = city.offset((y as usize, x as usize), outgoing_direction.delta())?;
+            let (straight_steps, turn_cost) = match turn {
+                Straight => (straight_steps + 1, 0),
+                _ => (1, rules.turn_cost),
+            };
+            let neighbor = DijkstraNode {
+                y: y as u16,
+                x: x as u16,
+                direction: Some(outgoing_direction),
+                straight_steps,
+            };
+            Some((neighbor, city[(y, x)] as u32 + turn_cost))
+        })
+        .collect()
+}
@@ -1,33 +1,53 @@
-use std::{collections::VecDeque, fs::read_to_string};
+use std::{collections::VecDeque, sync::OnceLock};
 
-fn main() {
-    let input = read_to_string("input/day18.txt").unwrap();
-    let (instructions_1, instructions_2): (Vec<(Direction, usize)>, Vec<(Direction, usize)>) =
-        input
-            .lines()
+use regex::Regex;
+
+use crate::{
+    direction::Direction,
+    parse::{self, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 18;
+
+pub struct Day18;
+
+pub struct DigPlan {
+    instructions_1: Vec<(Direction, usize)>,
+    instructions_2: Vec<(Direction, usize)>,
+}
+
+impl Solution for Day18 {
+    type Input = DigPlan;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let (instructions_1, instructions_2) = parse::lines(DAY, input)
             .map(|line| {
-                let [dir_str, count_str, code] = line.split(" ").collect::<Vec<_>>()[..] else {
-                    panic!("Parse error");
-                };
-                (
-                    (
-                        Direction::parse_part1(dir_str),
-                        str::parse(count_str).unwrap(),
-                    ),
-                    (
-                        Direction::parse_part2(code.chars().nth(7).unwrap()),
-                        usize::from_str_radix(&code[2..7], 16).unwrap(),
-                    ),
-                )
+                let caps = line.captures(instruction_re())?;
+                let (dir_str, count_str) = (&caps[1], caps.get(2).unwrap().as_str());
+                let (hex_str, hex_dir) = (&caps[3], &caps[4]);
+                let direction_1 = Direction::from_letter(dir_str.as_bytes()[0]).unwrap();
+                let direction_2 = Direction::from_hex_digit(hex_dir.as_bytes()[0]).unwrap();
+                Ok((
+                    (direction_1, line.number(count_str)?),
+                    (direction_2, usize::from_str_radix(hex_str, 16).unwrap()),
+                ))
             })
+            .collect::<Result<Vec<_>, ParseError>>()?
+            .into_iter()
             .unzip();
-    let ground = build_trench(&instructions_1);
-    let count_part1 = dugout_block_count(ground);
-    println!("Part 1: {count_part1}\n\n");
+        Ok(DigPlan { instructions_1, instructions_2 })
+    }
 
-    let ground = build_trench(&instructions_2);
-    let count_part2 = dugout_block_count(ground);
-    println!("Part 2: {count_part2}"); // 42708339569950
+    fn part1(&self, plan: &Self::Input) -> usize {
+        dugout_block_count(build_trench(&plan.instructions_1))
+    }
+
+    fn part2(&self, plan: &Self::Input) -> usize {
+        dugout_block_count(build_trench(&plan.instructions_2))
+    }
 }
 
 fn build_trench(instructions: &[(Direction, usize)]) -> VecDeque<Vec<u32>> {
@@ -61,25 +81,17 @@ fn build_trench(instructions: &[(Direction, usize)]) -> VecDeque<Vec<u32>> {
 
 fn dugout_block_count(ground: VecDeque<Vec<u32>>) -> usize {
     let mut dugout_count = 0_usize;
-    let print_skip = ground.len() / 20;
     for y in 1..ground.len() - 1 {
-        if y % print_skip == 0 {
-            println!("{:.1}% complete", 100.0 * y as f32 / ground.len() as f32);
-        }
         let row = &ground[y];
         let mut prev_trench_x = -1_i32;
         let mut inside = false;
         let mut expected_edge: Option<(u8, u8, u8)> = None;
         for trench_x in row {
             if *trench_x as i32 <= prev_trench_x {
-                println!("{trench_x} <= {prev_trench_x}");
                 continue;
             }
             dugout_count += 1;
             if inside {
-                if prev_trench_x < 0 {
-                    println!("prev_trench_x < 0: {prev_trench_x}");
-                }
                 dugout_count += (trench_x - (prev_trench_x as u32) - 1) as usize;
             }
             let symbol_above = match ground[y - 1].contains(trench_x) {
@@ -116,65 +128,7 @@ fn dugout_block_count(ground: VecDeque<Vec<u32>>) -> usize {
     dugout_count
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-use Direction::*;
-
-impl From<u8> for Direction {
-    fn from(value: u8) -> Self {
-        match value {
-            0 => Up,
-            1 => Right,
-            2 => Down,
-            3 => Left,
-            _ => panic!("{value}"),
-        }
-    }
-}
-
-impl From<Direction> for u8 {
-    fn from(value: Direction) -> Self {
-        match value {
-            Up => 0,
-            Right => 1,
-            Down => 2,
-            Left => 3,
-        }
-    }
-}
-
-impl Direction {
-    fn parse_part1(dir_str: &str) -> Self {
-        match dir_str {
-            "R" => Right,
-            "D" => Down,
-            "L" => Left,
-            "U" => Up,
-            _ => panic!("Invalid instruction!"),
-        }
-    }
-
-    fn parse_part2(dir_ch: char) -> Self {
-        match dir_ch {
-            '0' => Right,
-            '1' => Down,
-            '2' => Left,
-            '3' => Up,
-            _ => panic!("{dir_ch}"),
-        }
-    }
-
-    fn delta(&self) -> (isize, isize) {
-        match self {
-            Right => (0, 1),
-            Down => (1, 0),
-            Left => (0, -1),
-            Up => (-1_isize, 0_isize),
-        }
-    }
+fn instruction_re() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"^([RDLU]) (\d+) \(#([0-9a-f]{5})([0-3])\)$").unwrap())
 }
@@ -0,0 +1,273 @@
+use std::{cmp::Ordering, collections::HashMap, sync::OnceLock};
+
+use regex::Regex;
+
+use crate::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 19;
+
+pub struct Day19;
+
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    parts: Vec<Part>,
+}
+
+type Part = [u16; 4];
+type PartChoice = [(u16, u16); 4];
+
+struct Workflow {
+    rules: Vec<Rule>,
+    fallback: String,
+}
+
+struct Rule {
+    category: Category,
+    ord: Ordering,
+    limit: u16,
+    on_match: String,
+}
+
+impl Rule {
+    fn apply(&self, part: Part) -> Option<&str> {
+        let rating: u16 = part[usize::from(&self.category)];
+        (rating.cmp(&self.limit) == self.ord).then_some(&self.on_match)
+    }
+
+    fn negate(&self) -> Rule {
+        if self.ord == Ordering::Less {
+            Rule {
+                ord: Ordering::Greater,
+                limit: self.limit - 1,
+                category: self.category,
+                on_match: self.on_match.clone(),
+            }
+        } else {
+            Rule {
+                ord: Ordering::Less,
+                limit: self.limit + 1,
+                category: self.category,
+                on_match: self.on_match.clone(),
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+use Category::*;
+
+impl From<&Category> for usize {
+    fn from(value: &Category) -> Self {
+        match value {
+            X => 0,
+            M => 1,
+            A => 2,
+            S => 3,
+        }
+    }
+}
+
+impl From<&str> for Category {
+    fn from(value: &str) -> Self {
+        match value {
+            "x" => X,
+            "m" => M,
+            "a" => A,
+            "s" => S,
+            _ => panic!("Not a category: {value}"),
+        }
+    }
+}
+
+fn ordering_from(symbol: &str) -> Ordering {
+    match symbol {
+        "<" => Ordering::Less,
+        ">" => Ordering::Greater,
+        _ => panic!("Not an ordering: {symbol}"),
+    }
+}
+
+impl Solution for Day19 {
+    type Input = System;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let blocks = parse::blocks(DAY, input);
+        let [workflow_lines, part_lines] = &blocks[..] else {
+            return match blocks.get(2) {
+                Some(extra) => Err(extra[0].error(extra[0].text, "expected end of input")),
+                None => Err(parse::end_of_input(
+                    DAY,
+                    input,
+                    "expected workflows and parts",
+                )),
+            };
+        };
+
+        let parsed_workflows = workflow_lines
+            .iter()
+            .map(parse_workflow)
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        let names: Vec<&str> = parsed_workflows.iter().map(|(name, _)| *name).collect();
+        if !names.contains(&"in") {
+            return Err(workflow_lines[0].error("", "expected a workflow named \"in\""));
+        }
+        for (line, (_, workflow)) in workflow_lines.iter().zip(&parsed_workflows) {
+            let targets = workflow.rules.iter().map(|rule| &rule.on_match);
+            for target in targets.chain([&workflow.fallback]) {
+                if target != "A" && target != "R" && !names.contains(&target.as_str()) {
+                    let target_str = line.text.rfind(target.as_str()).map(|i| &line.text[i..]);
+                    return Err(
+                        line.error(target_str.unwrap_or(line.text), "expected a known workflow")
+                    );
+                }
+            }
+        }
+        let workflows: HashMap<String, Workflow> = parsed_workflows
+            .into_iter()
+            .map(|(name, workflow)| (name.to_string(), workflow))
+            .collect();
+
+        let parts: Vec<Part> = part_lines
+            .iter()
+            .map(|line| {
+                let caps = line.captures(part_re())?;
+                let mut part = [0; 4];
+                for (i, rating) in part.iter_mut().enumerate() {
+                    *rating = line.number(caps.get(i + 1).unwrap().as_str())?;
+                }
+                Ok(part)
+            })
+            .collect::<Result<_, ParseError>>()?;
+        Ok(System { workflows, parts })
+    }
+
+    fn part1(&self, system: &Self::Input) -> usize {
+        part1(&system.parts, &system.workflows)
+    }
+
+    fn part2(&self, system: &Self::Input) -> usize {
+        part2(&system.workflows)
+    }
+}
+
+fn parse_workflow<'a>(line: &Line<'a>) -> Result<(&'a str, Workflow), ParseError> {
+    let caps = line.captures(workflow_re())?;
+    let rules_str = caps.get(2).unwrap().as_str();
+    let rules = rules_str
+        .split(",")
+        .filter(|rule_str| !rule_str.is_empty())
+        .map(|rule_str| {
+            let groups = rule_re()
+                .captures(rule_str)
+                .ok_or_else(|| line.error(rule_str, "expected a rule like \"a<2006:qkq\""))?;
+            Ok(Rule {
+                category: groups[1].into(),
+                ord: ordering_from(&groups[2]),
+                limit: line.number(groups.get(3).unwrap().as_str())?,
+                on_match: groups[4].to_string(),
+            })
+        })
+        .collect::<Result<_, ParseError>>()?;
+    let fallback = caps[3].to_string();
+    Ok((caps.get(1).unwrap().as_str(), Workflow { rules, fallback }))
+}
+
+fn workflow_re() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"^([a-z]+)\{(.*?),?([a-zAR]+)\}$").unwrap())
+}
+
+fn rule_re() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"^([xmas])([<>])(\d+):([a-zAR]+)$").unwrap())
+}
+
+fn part_re() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"^\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)\}$").unwrap())
+}
+
+fn part1(parts: &[[u16; 4]], workflows: &HashMap<String, Workflow>) -> usize {
+    let mut accepted: Vec<Part> = Vec::new();
+    'part_loop: for part in parts {
+        let mut workflow = &workflows["in"];
+        'workflow_loop: loop {
+            let outcome = workflow
+                .rules
+                .iter()
+                .find_map(|rule| rule.apply(*part))
+                .unwrap_or(&workflow.fallback);
+            match outcome {
+                "R" => continue 'part_loop,
+                "A" => {
+                    accepted.push(*part);
+                    continue 'part_loop;
+                }
+                next_workflow => {
+                    workflow = &workflows[next_workflow];
+                    continue 'workflow_loop;
+                }
+            }
+        }
+    }
+    accepted
+        .into_iter()
+        .map(|part| part.into_iter().map(|n| n as usize).sum::<usize>())
+        .sum::<usize>()
+}
+
+fn part2(workflows: &HashMap<String, Workflow>) -> usize {
+    let start_wf = workflows.get("in").unwrap();
+    let mut todo_list: Vec<(&Workflow, PartChoice)> = Vec::new();
+    todo_list.push((start_wf, [(1, 4000), (1, 4000), (1, 4000), (1, 4000)]));
+    let mut combination_count = 0_usize;
+    while let Some((wf, mut part_choice)) = todo_list.pop() {
+        for rule in wf.rules.iter() {
+            let restricted_on_match = restrict_choice(part_choice, rule);
+            match &*rule.on_match {
+                "R" => {}
+                "A" => combination_count += count_combinations(restricted_on_match),
+                other => {
+                    todo_list.push((workflows.get(other).unwrap(), restricted_on_match));
+                }
+            };
+            part_choice = restrict_choice(part_choice, &rule.negate());
+        }
+        match &*wf.fallback {
+            "R" => {}
+            "A" => combination_count += count_combinations(part_choice),
+            other => todo_list.push((workflows.get(other).unwrap(), part_choice)),
+        };
+    }
+    combination_count
+}
+
+fn count_combinations(part_choice: PartChoice) -> usize {
+    part_choice
+        .into_iter()
+        .map(|(low, high)| (high - low + 1) as usize)
+        .reduce(|acc, count| acc * count)
+        .unwrap()
+}
+
+fn restrict_choice(mut part_choice: PartChoice, rule: &Rule) -> PartChoice {
+    let Rule { category, ord, limit, .. } = rule;
+    let (low, high) = &mut part_choice[usize::from(category)];
+    match ord {
+        Ordering::Less => *high = limit - 1,
+        Ordering::Greater => *low = limit + 1,
+        Ordering::Equal => panic!(),
+    };
+    part_choice
+}
@@ -0,0 +1,560 @@
+use std::{
+    any::Any,
+    collections::{BTreeMap, HashMap, VecDeque},
+    fmt::{self, Write},
+    ops::ControlFlow,
+};
+
+use crate::{
+    math,
+    parse::{self, ParseError},
+    Fallible, Solution,
+};
+
+const DAY: u8 = 20;
+const BUTTON: &str = "button";
+const BROADCASTER: &str = "broadcaster";
+const RX: &str = "rx";
+
+/// Parses the modules of the puzzle input. Every prefix in `module_types` declares a module
+/// type, like `%` for flip-flops: `%a -> b` is a flip-flop named `a`. A module type can take an
+/// argument after a slash, like the period of a counter in `#a/3 -> b`. Apart from the
+/// broadcaster, every declared module needs a type.
+pub struct Day20 {
+    pub module_types: HashMap<char, ModuleFactory>,
+    /// How often part 2 presses the button at most, waiting for the inputs of the conjunction
+    /// feeding `rx` to get a low pulse.
+    pub max_presses: usize,
+}
+
+impl Default for Day20 {
+    /// The flip-flops and conjunctions of the puzzle, plus inverters (`!`), counters (`#`) and
+    /// delays (`~`).
+    fn default() -> Self {
+        let module_types: [(char, ModuleFactory); 5] = [
+            ('%', |_| Ok(Box::new(FlipFlop { state: false }))),
+            ('&', |_| {
+                Ok(Box::new(Conjunction { inputs: BTreeMap::new() }))
+            }),
+            ('!', |_| Ok(Box::new(Inverter))),
+            ('#', Counter::from_argument),
+            ('~', |_| Ok(Box::new(Delay { previous: false }))),
+        ];
+        Day20 {
+            module_types: module_types.into(),
+            max_presses: 100_000,
+        }
+    }
+}
+
+/// Creates the behavior of a newly declared module from its argument, if it has one.
+pub type ModuleFactory = fn(Option<&str>) -> Result<Box<dyn ModuleBehavior>, String>;
+
+/// What a module does with the pulses it receives.
+pub trait ModuleBehavior: Any + CloneBehavior {
+    /// Tells the module about one of the modules sending pulses to it, before the first press.
+    fn add_input(&mut self, _name: &str) {}
+
+    /// Handles a pulse from `sender_name`. Returns the pulse to send to all outputs, if any.
+    fn receive(&mut self, sender_name: &str, value: bool) -> Option<bool>;
+
+    /// Describes the state of the module, or returns `None` if it's stateless.
+    fn state(&self) -> Option<String> {
+        None
+    }
+
+    /// The Graphviz shape of the module's node.
+    fn dot_shape(&self) -> &'static str {
+        "ellipse"
+    }
+}
+
+/// Lets the modules of a parsed input be copied for each simulation.
+pub trait CloneBehavior {
+    fn clone_behavior(&self) -> Box<dyn ModuleBehavior>;
+}
+
+impl<T: ModuleBehavior + Clone> CloneBehavior for T {
+    fn clone_behavior(&self) -> Box<dyn ModuleBehavior> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn ModuleBehavior> {
+    fn clone(&self) -> Self {
+        self.clone_behavior()
+    }
+}
+
+#[derive(Clone)]
+pub struct Module {
+    pub outputs: Vec<String>,
+    pub behavior: Box<dyn ModuleBehavior>,
+}
+
+/// Sends every pulse on.
+#[derive(Clone)]
+pub struct Broadcaster;
+
+/// Receives pulses and ignores them, like modules that are only named as outputs.
+#[derive(Clone)]
+pub struct Sink;
+
+/// Ignores high pulses. A low pulse flips it on or off, and it sends a high pulse if it's now
+/// on.
+#[derive(Clone)]
+pub struct FlipFlop {
+    pub state: bool,
+}
+
+/// Remembers the last pulse of each input and sends a low pulse if they were all high.
+#[derive(Clone)]
+pub struct Conjunction {
+    pub inputs: BTreeMap<String, bool>,
+}
+
+/// Sends the opposite of every pulse.
+#[derive(Clone)]
+pub struct Inverter;
+
+/// Counts the pulses it receives and sends a high pulse for every `period`th, a low one for the
+/// others.
+#[derive(Clone)]
+pub struct Counter {
+    pub period: usize,
+    pub count: usize,
+}
+
+/// Sends the pulse it received the time before, starting with a low one.
+#[derive(Clone)]
+pub struct Delay {
+    pub previous: bool,
+}
+
+impl ModuleBehavior for Broadcaster {
+    fn receive(&mut self, _sender_name: &str, value: bool) -> Option<bool> {
+        Some(value)
+    }
+
+    fn dot_shape(&self) -> &'static str {
+        "doublecircle"
+    }
+}
+
+impl ModuleBehavior for Sink {
+    fn receive(&mut self, _sender_name: &str, _value: bool) -> Option<bool> {
+        None
+    }
+
+    fn dot_shape(&self) -> &'static str {
+        "plaintext"
+    }
+}
+
+impl ModuleBehavior for FlipFlop {
+    fn receive(&mut self, _sender_name: &str, value: bool) -> Option<bool> {
+        if value {
+            return None;
+        }
+        self.state = !self.state;
+        Some(self.state)
+    }
+
+    fn state(&self) -> Option<String> {
+        Some(if self.state { "on" } else { "off" }.to_string())
+    }
+
+    fn dot_shape(&self) -> &'static str {
+        "box"
+    }
+}
+
+impl ModuleBehavior for Conjunction {
+    fn add_input(&mut self, name: &str) {
+        self.inputs.insert(name.to_string(), false);
+    }
+
+    fn receive(&mut self, sender_name: &str, value: bool) -> Option<bool> {
+        if let Some(memory) = self.inputs.get_mut(sender_name) {
+            *memory = value;
+        }
+        Some(!self.inputs.values().all(|&v| v))
+    }
+
+    fn state(&self) -> Option<String> {
+        let memory: Vec<String> = self
+            .inputs
+            .iter()
+            .map(|(input, &value)| format!("{input}={}", pulse_name(value)))
+            .collect();
+        Some(memory.join(", "))
+    }
+
+    fn dot_shape(&self) -> &'static str {
+        "invtrapezium"
+    }
+}
+
+impl ModuleBehavior for Inverter {
+    fn receive(&mut self, _sender_name: &str, value: bool) -> Option<bool> {
+        Some(!value)
+    }
+
+    fn dot_shape(&self) -> &'static str {
+        "invtriangle"
+    }
+}
+
+impl Counter {
+    fn from_argument(argument: Option<&str>) -> Result<Box<dyn ModuleBehavior>, String> {
+        let period = match argument {
+            Some(period) => period
+                .parse()
+                .ok()
+                .filter(|&period| period > 0)
+                .ok_or("expected a positive period")?,
+            None => 2,
+        };
+        Ok(Box::new(Counter { period, count: 0 }))
+    }
+}
+
+impl ModuleBehavior for Counter {
+    fn receive(&mut self, _sender_name: &str, _value: bool) -> Option<bool> {
+        self.count = (self.count + 1) % self.period;
+        Some(self.count == 0)
+    }
+
+    fn state(&self) -> Option<String> {
+        Some(format!("{}/{}", self.count, self.period))
+    }
+
+    fn dot_shape(&self) -> &'static str {
+        "octagon"
+    }
+}
+
+impl ModuleBehavior for Delay {
+    fn receive(&mut self, _sender_name: &str, value: bool) -> Option<bool> {
+        Some(std::mem::replace(&mut self.previous, value))
+    }
+
+    fn state(&self) -> Option<String> {
+        Some(pulse_name(self.previous).to_string())
+    }
+
+    fn dot_shape(&self) -> &'static str {
+        "cds"
+    }
+}
+
+fn pulse_name(value: bool) -> &'static str {
+    if value {
+        "high"
+    } else {
+        "low"
+    }
+}
+
+/// A pulse sent during button press number `press`, counting from 1. `value` is true for a
+/// high pulse.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Signal {
+    pub press: usize,
+    pub sender_name: String,
+    pub receiver_name: String,
+    pub value: bool,
+}
+
+/// Shows the signal like the puzzle does, e.g. `broadcaster -low-> a`.
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = pulse_name(self.value);
+        write!(f, "{} -{value}-> {}", self.sender_name, self.receiver_name)
+    }
+}
+
+/// Presses the button of a module network and delivers the resulting pulses.
+pub struct Simulator {
+    modules: HashMap<String, Module>,
+    presses: usize,
+}
+
+/// The state of every module with a state after `presses` presses.
+#[derive(PartialEq, Eq, Debug)]
+pub struct Snapshot {
+    pub presses: usize,
+    pub states: BTreeMap<String, String>,
+}
+
+impl fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "After {} presses:", self.presses)?;
+        for (name, state) in &self.states {
+            write!(f, "\n{name}: {state}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day20 {
+    type Input = HashMap<String, Module>;
+    type Answer1 = usize;
+    type Answer2 = Fallible<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut modules: HashMap<String, Module> = HashMap::new();
+        for line in parse::lines(DAY, input) {
+            let (declaration, output_str) = line.split_once(line.text, " -> ")?;
+            let outputs = output_str
+                .split(", ")
+                .map(|x| match is_module_name(x) {
+                    true => Ok(x.to_string()),
+                    false => Err(line.error(x, "expected a module name")),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let mut chars = declaration.chars();
+            let factory = chars
+                .next()
+                .and_then(|prefix| self.module_types.get(&prefix));
+            let (name, behavior): (&str, Box<dyn ModuleBehavior>) = match factory {
+                Some(factory) => {
+                    let (name, argument) = match chars.as_str().split_once('/') {
+                        Some((name, argument)) => (name, Some(argument)),
+                        None => (chars.as_str(), None),
+                    };
+                    let behavior = factory(argument).map_err(|e| line.error(declaration, e))?;
+                    (name, behavior)
+                }
+                None if declaration == BROADCASTER => (declaration, Box::new(Broadcaster)),
+                None => return Err(line.error(declaration, "unknown module type")),
+            };
+            if !is_module_name(name) {
+                return Err(line.error(declaration, "expected a module name"));
+            }
+            let module = Module { outputs, behavior };
+            if modules.insert(name.to_string(), module).is_some() {
+                return Err(line.error(declaration, "duplicate module"));
+            }
+        }
+        if !modules.contains_key(BROADCASTER) {
+            return Err(parse::end_of_input(DAY, input, "expected a broadcaster"));
+        }
+        for name in modules.keys().cloned().collect::<Vec<_>>() {
+            for output_name in modules[&name].outputs.clone() {
+                modules
+                    .entry(output_name)
+                    .or_insert_with(|| Module { outputs: vec![], behavior: Box::new(Sink) })
+                    .behavior
+                    .add_input(&name);
+            }
+        }
+        Ok(modules)
+    }
+
+    fn part1(&self, modules: &Self::Input) -> usize {
+        let mut simulator = Simulator::new(modules);
+        let (mut low_signal_total, mut high_signal_total) = (0, 0);
+        for _ in 0..1000 {
+            let signals = simulator.press();
+            let high_signal_count = signals.iter().filter(|signal| signal.value).count();
+            low_signal_total += signals.len() - high_signal_count;
+            high_signal_total += high_signal_count;
+        }
+        low_signal_total * high_signal_total
+    }
+
+    fn part2(&self, modules: &Self::Input) -> Fallible<usize> {
+        first_rx_low_pulse(modules, self.max_presses).into()
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "max_presses" => self.max_presses = parse::setting(name, value)?,
+            _ => return Err(format!("Unknown setting: {name}")),
+        }
+        Ok(())
+    }
+
+    /// Every module type has its own shape, flip-flops are drawn as boxes and conjunctions as
+    /// trapezia.
+    fn to_dot(&self, modules: &Self::Input) -> Option<String> {
+        let mut names: Vec<&String> = modules.keys().collect();
+        names.sort();
+        let mut dot = String::from("digraph modules {\n");
+        for name in names {
+            let Module { outputs, behavior } = &modules[name];
+            let shape = behavior.dot_shape();
+            writeln!(dot, "    \"{name}\" [shape={shape}];").unwrap();
+            for output in outputs {
+                writeln!(dot, "    \"{name}\" -> \"{output}\";").unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        Some(dot)
+    }
+}
+
+/// `rx` is fed by a single conjunction, which sends it a low pulse once all of its inputs
+/// sent it a high pulse. Each input does that periodically, in the press it first receives a
+/// low pulse itself, so `rx` gets its low pulse after the LCM of those presses. Gives up after
+/// `max_presses` presses.
+pub fn first_rx_low_pulse(
+    modules: &HashMap<String, Module>,
+    max_presses: usize,
+) -> Result<usize, String> {
+    let feeder = rx_feeder(modules)?;
+    let mut first_low_pulses: HashMap<&str, usize> =
+        inputs(modules, feeder).map(|name| (name, 0)).collect();
+    if first_low_pulses.is_empty() {
+        return Err(format!("Nothing sends pulses to {feeder}"));
+    }
+    let mut simulator = Simulator::new(modules);
+    while first_low_pulses.values().any(|&press| press == 0) {
+        if simulator.presses() == max_presses {
+            let mut silent: Vec<&str> = first_low_pulses
+                .iter()
+                .filter(|(_, &press)| press == 0)
+                .map(|(&name, _)| name)
+                .collect();
+            silent.sort();
+            return Err(format!(
+                "{} got no low pulse in {} presses",
+                silent.join(", "),
+                max_presses
+            ));
+        }
+        simulator.press_with(|signal| {
+            if !signal.value {
+                if let Some(first_press @ 0) = first_low_pulses.get_mut(&*signal.receiver_name) {
+                    *first_press = signal.press;
+                }
+            }
+            ControlFlow::Continue(())
+        });
+    }
+    Ok(first_low_pulses.into_values().fold(1, math::lcm))
+}
+
+fn is_module_name(name: &str) -> bool {
+    !name.is_empty() && name.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+/// The names of the modules sending pulses to `receiver_name`.
+fn inputs<'a>(
+    modules: &'a HashMap<String, Module>,
+    receiver_name: &'a str,
+) -> impl Iterator<Item = &'a str> {
+    modules
+        .iter()
+        .filter(move |(_, module)| module.outputs.iter().any(|output| output == receiver_name))
+        .map(|(name, _)| name.as_str())
+}
+
+/// The name of the conjunction that is the only module sending pulses to `rx`.
+fn rx_feeder(modules: &HashMap<String, Module>) -> Result<&str, String> {
+    let mut feeders = inputs(modules, RX);
+    match (feeders.next(), feeders.next()) {
+        (Some(name), None) => {
+            let behavior: &dyn Any = &*modules[name].behavior;
+            match behavior.is::<Conjunction>() {
+                true => Ok(name),
+                false => Err(format!("{name} feeds {RX} but isn't a conjunction")),
+            }
+        }
+        (None, _) => Err(format!("No module feeds {RX}")),
+        (Some(_), Some(_)) => Err(format!("More than one module feeds {RX}")),
+    }
+}
+
+impl Simulator {
+    /// Starts with the modules in their parsed state: flip-flops off and conjunctions
+    /// remembering low pulses.
+    pub fn new(modules: &HashMap<String, Module>) -> Self {
+        Simulator { modules: modules.clone(), presses: 0 }
+    }
+
+    /// How often the button has been pressed.
+    pub fn presses(&self) -> usize {
+        self.presses
+    }
+
+    /// Presses the button once and returns every pulse in the order it was delivered.
+    pub fn press(&mut self) -> Vec<Signal> {
+        let mut signals = Vec::new();
+        self.press_with(|signal| {
+            signals.push(signal.clone());
+            ControlFlow::Continue(())
+        });
+        signals
+    }
+
+    /// Every pulse of the next `presses` button presses, pressing the button lazily.
+    pub fn trace(&mut self, presses: usize) -> impl Iterator<Item = Signal> + '_ {
+        (0..presses).flat_map(|_| self.press())
+    }
+
+    /// Presses the button until `receiver_name` receives a pulse of `value`, at most
+    /// `max_presses` times. The simulation stops right after that pulse was delivered, so the
+    /// pulses it caused are never sent. Returns the pulse if it was received.
+    pub fn run_until(
+        &mut self,
+        receiver_name: &str,
+        value: bool,
+        max_presses: usize,
+    ) -> Option<Signal> {
+        for _ in 0..max_presses {
+            let mut found = None;
+            self.press_with(|signal| {
+                if signal.receiver_name == receiver_name && signal.value == value {
+                    found = Some(signal.clone());
+                    return ControlFlow::Break(());
+                }
+                ControlFlow::Continue(())
+            });
+            if found.is_some() {
+                return found;
+            }
+        }
+        None
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        let states = self
+            .modules
+            .iter()
+            .filter_map(|(name, module)| Some((name.clone(), module.behavior.state()?)))
+            .collect();
+        Snapshot { presses: self.presses, states }
+    }
+
+    /// Presses the button once, calling `on_signal` after each pulse was delivered. The press
+    /// is abandoned when `on_signal` breaks.
+    pub fn press_with(&mut self, mut on_signal: impl FnMut(&Signal) -> ControlFlow<()>) {
+        self.presses += 1;
+        let mut signal_queue = VecDeque::new();
+        signal_queue.push_back(Signal {
+            press: self.presses,
+            sender_name: BUTTON.to_string(),
+            receiver_name: BROADCASTER.to_string(),
+            value: false,
+        });
+        while let Some(signal) = signal_queue.pop_front() {
+            let Signal { press, ref sender_name, ref receiver_name, value } = signal;
+            let Module { outputs, behavior } = self.modules.get_mut(receiver_name).unwrap();
+            if let Some(value) = behavior.receive(sender_name, value) {
+                for output_name in outputs.iter() {
+                    signal_queue.push_back(Signal {
+                        press,
+                        sender_name: receiver_name.clone(),
+                        receiver_name: output_name.clone(),
+                        value,
+                    });
+                }
+            }
+            if on_signal(&signal).is_break() {
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    grid::{Grid, NEIGHBORS4},
+    parse::{self, ParseError},
+    search, Fallible, Solution,
+};
+
+const DAY: u8 = 21;
+const PART2_STEPS: usize = 26_501_365;
+
+pub struct Day21 {
+    pub steps: usize,
+}
+
+impl Default for Day21 {
+    fn default() -> Self {
+        Day21 { steps: 64 }
+    }
+}
+
+impl Solution for Day21 {
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = Fallible<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let grid = parse::grid(DAY, input, b".#S")?;
+        let mut lines = parse::lines(DAY, input);
+        if grid.height() > grid.width() {
+            let extra = lines.nth(grid.width()).unwrap();
+            return Err(extra.error(extra.text, "expected a square grid"));
+        }
+        let first_line = lines.next().unwrap();
+        if grid.height() < grid.width() {
+            return Err(first_line.error(first_line.text, "expected a square grid"));
+        }
+        if !grid.cells().any(|&b| b == b'S') {
+            return Err(first_line.error(first_line.text, "expected a start tile \"S\""));
+        }
+        Ok(grid)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> usize {
+        reachable_count(grid, self.steps)
+    }
+
+    /// The start is in the middle of an empty row and column, so after the first
+    /// `PART2_STEPS % size` steps the reachable area grows by a whole copy of the grid in each
+    /// direction every `size` steps, and the reachable count is quadratic in the number of
+    /// copies. We fit it on the first three copy counts, which fails for grids that don't grow
+    /// like that.
+    fn part2(&self, grid: &Self::Input) -> Fallible<usize> {
+        far_reachable_count(grid).into()
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "steps" => self.steps = parse::setting(name, value)?,
+            _ => return Err(format!("Unknown setting: {name}")),
+        }
+        Ok(())
+    }
+}
+
+fn far_reachable_count(grid: &Grid<u8>) -> Result<usize, String> {
+    let size = grid.height();
+    let n = (PART2_STEPS / size) as i128;
+    let [f0, f1, f2] =
+        [0, 1, 2].map(|i| reachable_count(grid, PART2_STEPS % size + i * size) as i128);
+    let count = f0 + n * (f1 - f0) + n * (n - 1) / 2 * (f2 + f0 - 2 * f1);
+    usize::try_from(count)
+        .map_err(|_| format!("The reachable counts {f0}, {f1} and {f2} don't grow quadratically"))
+}
+
+/// Counts the garden plots reachable in exactly `max_steps` steps on the grid repeated infinitely
+/// in every direction. Those are the plots at most `max_steps` away with the same parity, because
+/// the elf can step back and forth to waste two steps.
+fn reachable_count(grid: &Grid<u8>, max_steps: usize) -> usize {
+    let (height, width) = (grid.height() as isize, grid.width() as isize);
+    let (start, _) = grid.iter().find(|&(_, &plot)| plot == b'S').unwrap();
+    let start = (start.0 as isize, start.1 as isize);
+    let paths = search::bfs_within(
+        [start],
+        |&(y, x)| {
+            NEIGHBORS4
+                .into_iter()
+                .map(move |(dy, dx)| (y + dy, x + dx))
+                .filter(|&(y, x)| {
+                    let pos = (y.rem_euclid(height) as usize, x.rem_euclid(width) as usize);
+                    grid[pos] != b'#'
+                })
+        },
+        max_steps,
+    );
+    paths
+        .distances()
+        .values()
+        .filter(|&&steps| steps % 2 == max_steps % 2)
+        .count()
+}
@@ -1,32 +1,84 @@
+#![allow(clippy::needless_range_loop)]
+
 use std::{
     collections::{HashMap, HashSet, VecDeque},
-    fs::read_to_string,
+    sync::OnceLock,
 };
 
 use regex::Regex;
 
+use crate::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 22;
+
 const X_SIZE: usize = 10;
 const Y_SIZE: usize = 10;
 const Z_SIZE: usize = 375;
 
 type Grid = [[[u16; Z_SIZE]; Y_SIZE]; X_SIZE];
+type Brick = [usize; 6];
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Brick>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(DAY, input)
+            .map(|line| {
+                let caps = line.captures(brick_re())?;
+                let mut brick: Brick = [0; 6];
+                for (i, coord) in brick.iter_mut().enumerate() {
+                    *coord = line.number(caps.get(i + 1).unwrap().as_str())?;
+                }
+                let [start_x, start_y, start_z, end_x, end_y, end_z] = brick;
+                if start_x > end_x || start_y > end_y || start_z > end_z {
+                    return Err(line.error(line.text, "expected the start to precede the end"));
+                }
+                if start_z == 0 || end_z >= Z_SIZE {
+                    return Err(line.error(
+                        line.text,
+                        format!("expected heights between 1 and {}", Z_SIZE - 1),
+                    ));
+                }
+                Ok(brick)
+            })
+            .collect()
+    }
 
-fn main() {
-    let input = read_to_string("input/day22.txt").unwrap();
-    let mut grid: Grid = [[[0_u16; Z_SIZE]; Y_SIZE]; X_SIZE];
-    let block_re = Regex::new(r"(\d),(\d),(\d+)~(\d),(\d),(\d+)").unwrap();
-    for (i, line) in input.lines().enumerate() {
-        let [start_x, start_y, start_z, end_x, end_y, end_z, ..] = *block_re
-            .captures(line)
-            .unwrap()
+    fn part1(&self, bricks: &Self::Input) -> usize {
+        let (supporting_brick_counts, supported_bricks) = settle(bricks);
+        supported_bricks
             .iter()
-            .skip(1)
-            .filter_map(|x| x)
-            .map(|m| m.as_str().parse::<usize>().unwrap())
-            .collect::<Vec<_>>()
-        else {
-            panic!("Parse error");
-        };
+            .filter(|&(_, supported_ids)| {
+                !supported_ids
+                    .iter()
+                    .any(|id| supporting_brick_counts[id] == 1)
+            })
+            .count()
+    }
+
+    fn part2(&self, bricks: &Self::Input) -> usize {
+        let (supporting_brick_counts, supported_bricks) = settle(bricks);
+        count_all_cascading_bricks(supporting_brick_counts, supported_bricks)
+    }
+}
+
+fn brick_re() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"^(\d),(\d),(\d+)~(\d),(\d),(\d+)$").unwrap())
+}
+
+/// Lets the bricks fall into place and returns, for each brick, how many bricks support it and
+/// which bricks it supports.
+fn settle(bricks: &[Brick]) -> (HashMap<u16, u16>, HashMap<u16, HashSet<u16>>) {
+    let mut grid: Box<Grid> = Box::new([[[0_u16; Z_SIZE]; Y_SIZE]; X_SIZE]);
+    for (i, &[start_x, start_y, start_z, end_x, end_y, end_z]) in bricks.iter().enumerate() {
         for x in start_x..=end_x {
             for y in start_y..=end_y {
                 for z in start_z..=end_z {
@@ -35,27 +87,12 @@ fn main() {
             }
         }
     }
-    let_bricks_fall(&mut grid);
-    let (supporting_brick_counts, supported_bricks) = analyze_dependencies(&grid);
-    // let removable_bricks = find_removable_bricks(&grid);
-    let removable_bricks = supported_bricks
-        .iter()
-        .filter(|&(_, supported_ids)| {
-            supported_ids
-                .iter()
-                .filter(|id| supporting_brick_counts[id] == 1)
-                .next()
-                .is_none()
-        })
-        .count();
-    println!("Part 1: {}", removable_bricks); // 482
-    let cascading_brick_total_count =
-        count_all_cascading_bricks(supporting_brick_counts, supported_bricks);
-    println!("Part 2: {cascading_brick_total_count}"); // 103010
+    let_bricks_fall(&mut grid, bricks.len());
+    analyze_dependencies(&grid)
 }
 
-fn let_bricks_fall(grid: &mut Grid) {
-    let mut fallen_bricks = [false; 1494];
+fn let_bricks_fall(grid: &mut Grid, brick_count: usize) {
+    let mut fallen_bricks = vec![false; brick_count + 1];
     for z in 1..Z_SIZE {
         for x in 0..X_SIZE {
             for y in 0..Y_SIZE {
@@ -64,12 +101,11 @@ fn let_bricks_fall(grid: &mut Grid) {
                     continue;
                 }
                 fallen_bricks[brick_id as usize] = true;
-                if let Some(vertical_brick_top) = (z + 1..Z_SIZE)
-                    .filter(|&z_prime| grid[x][y][z_prime] == brick_id)
-                    .last()
+                if let Some(vertical_brick_top) =
+                    (z + 1..Z_SIZE).rfind(|&z_prime| grid[x][y][z_prime] == brick_id)
                 {
                     // move vertical brick
-                    let fall_distance = fall_distance(&grid, x, y, z);
+                    let fall_distance = fall_distance(grid, x, y, z);
                     if fall_distance > 0 {
                         let column = &mut grid[x][y];
                         for brick_z in z..=vertical_brick_top {
@@ -79,10 +115,10 @@ fn let_bricks_fall(grid: &mut Grid) {
                     }
                 } else {
                     // move horizontal brick
-                    let brick_coords = all_brick_coords(&grid, x, y, z);
+                    let brick_coords = all_brick_coords(grid, x, y, z);
                     let fall_distance = brick_coords
                         .iter()
-                        .map(|&(brick_x, brick_y)| fall_distance(&grid, brick_x, brick_y, z))
+                        .map(|&(brick_x, brick_y)| fall_distance(grid, brick_x, brick_y, z))
                         .min()
                         .unwrap();
                     if fall_distance > 0 {
@@ -137,8 +173,6 @@ fn analyze_dependencies(grid: &Grid) -> (HashMap<u16, u16>, HashMap<u16, HashSet
             }
         }
     }
-    let supporting_brick_counts = supporting_brick_counts;
-    let supported_bricks = supported_bricks;
     (supporting_brick_counts, supported_bricks)
 }
 
@@ -151,10 +185,7 @@ fn count_all_cascading_bricks(
         let mut local_supporting_counts = HashMap::<u16, u16>::new();
         let mut cascading_bricks = VecDeque::<u16>::new();
         cascading_bricks.push_back(*brick_id);
-        loop {
-            let Some(falling_id) = cascading_bricks.pop_front() else {
-                break;
-            };
+        while let Some(falling_id) = cascading_bricks.pop_front() {
             for supported_id in supported_bricks.get(&falling_id).unwrap() {
                 let supporting_count = local_supporting_counts
                     .entry(*supported_id)
@@ -189,8 +220,7 @@ fn fall_distance(grid: &Grid, x: usize, y: usize, z: usize) -> usize {
     let column = grid[x][y];
     let bottom_z = (0..z)
         .rev()
-        .filter(|&z_prime| z_prime == 0 || column[z_prime] != 0)
-        .next()
+        .find(|&z_prime| z_prime == 0 || column[z_prime] != 0)
         .unwrap();
     z - bottom_z - 1
 }
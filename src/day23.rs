@@ -0,0 +1,195 @@
+use std::{
+    cmp::max,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Write,
+};
+
+use crate::{
+    direction::{
+        Direction::{self, *},
+        Turn::*,
+    },
+    grid::{Grid, Pos},
+    parse::{self, Line, ParseError},
+    Solution,
+};
+
+const DAY: u8 = 23;
+
+type SearchState = (Pos, Direction);
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let grid = parse::grid(DAY, input, b".#<>^v")?;
+        let (width, height) = (grid.width(), grid.height());
+        let lines: Vec<Line> = parse::lines(DAY, input).collect();
+        let (first_line, last_line) = (lines[0], lines[height - 1]);
+        if width < 3 || height < 2 {
+            return Err(first_line.error(first_line.text, "expected at least 2 rows of 3 tiles"));
+        }
+        if grid[(0, 1)] != b'.' {
+            let tile = &first_line.text[1..2];
+            return Err(first_line.error(tile, "expected a path next to the top left corner"));
+        }
+        if grid[(height - 1, width - 2)] != b'.' {
+            let tile = &last_line.text[width - 2..width - 1];
+            return Err(last_line.error(tile, "expected a path next to the bottom right corner"));
+        }
+        Ok(grid)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> usize {
+        let (start_d, start) = (South, (0, 1));
+        let end = (grid.height() - 1, grid.width() - 2);
+
+        let mut longest_path_len = 0;
+        let mut todo = VecDeque::new();
+        todo.push_back((0, start_d, start));
+        while let Some((path_len, d, pos)) = todo.pop_front() {
+            if pos == end {
+                longest_path_len = max(longest_path_len, path_len);
+            }
+            let neighbors = all_neighbors(grid, pos, d);
+            for (next_d, next_coords) in neighbors {
+                todo.push_back((path_len + 1, next_d, next_coords));
+            }
+        }
+        longest_path_len
+    }
+
+    fn part2(&self, grid: &Self::Input) -> usize {
+        let enter_tile = (0, 1);
+        let exit_tile = (grid.height() - 1, grid.width() - 2);
+
+        let mut max_distance = 0_usize;
+        let incidence_map = crossroads_graph(grid, enter_tile, exit_tile);
+        let mut todo = Vec::<(Pos, HashSet<Pos>, usize)>::new();
+        todo.push((enter_tile, HashSet::new(), 0));
+        while let Some((tile, visited_tiles, traveled_distance)) = todo.pop() {
+            if tile == exit_tile {
+                if traveled_distance > max_distance {
+                    max_distance = traveled_distance;
+                }
+                continue;
+            }
+            if visited_tiles.contains(&tile) {
+                continue;
+            }
+            let Some(neighs) = incidence_map.get(&tile) else {
+                panic!("{tile:?} not found");
+            };
+            for &(neigh_tile, distance) in neighs {
+                let mut visited_tiles = visited_tiles.clone();
+                visited_tiles.insert(tile);
+                todo.push((neigh_tile, visited_tiles, traveled_distance + distance));
+            }
+        }
+        max_distance
+    }
+
+    /// The crossroads graph of part 2, with the distances between crossroads as edge labels.
+    fn to_dot(&self, grid: &Self::Input) -> Option<String> {
+        let enter_tile = (0, 1);
+        let exit_tile = (grid.height() - 1, grid.width() - 2);
+        let mut incidences: Vec<_> = crossroads_graph(grid, enter_tile, exit_tile)
+            .into_iter()
+            .collect();
+        incidences.sort();
+        let mut dot = String::from("digraph crossroads {\n");
+        for ((y, x), neighbors) in incidences {
+            for ((neighbor_y, neighbor_x), distance) in neighbors {
+                writeln!(
+                    dot,
+                    "    \"{y},{x}\" -> \"{neighbor_y},{neighbor_x}\" [label={distance}];"
+                )
+                .unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        Some(dot)
+    }
+}
+
+fn all_neighbors(grid: &Grid<u8>, pos: Pos, d: Direction) -> VecDeque<(Direction, Pos)> {
+    [Straight, Left, Right]
+        .into_iter()
+        .map(|turn| d.after_turn(turn))
+        .filter_map(|d| Some((d, grid.offset(pos, d.delta())?)))
+        .filter(|&(d, pos)| [b'.', d.to_arrow()].contains(&grid[pos]))
+        .collect()
+}
+
+fn crossroads_graph(
+    grid: &Grid<u8>,
+    enter_tile: Pos,
+    exit_tile: Pos,
+) -> HashMap<Pos, Vec<(Pos, usize)>> {
+    let mut incidence_map = HashMap::<Pos, Vec<(Pos, usize)>>::new();
+    let mut visited_crossroads = HashSet::<Pos>::new();
+    let mut todo = Vec::<SearchState>::new();
+    todo.push((enter_tile, South));
+    while let Some((start_tile, mut direction)) = todo.pop() {
+        let Some(mut curr_tile) = grid.offset(start_tile, direction.delta()) else {
+            continue;
+        };
+        let mut distance = 1;
+
+        // find the next node (crossing or exit) and connect it to (start_y, start_x)
+        loop {
+            if curr_tile == exit_tile {
+                incidence_map
+                    .entry(start_tile)
+                    .or_default()
+                    .push((curr_tile, distance));
+                break;
+            }
+            let next_steps = available_steps(grid, (curr_tile, direction));
+            if next_steps.is_empty() {
+                break;
+            }
+            if let [(next_d, next_tile)] = next_steps[..] {
+                // we're in a corridor, follow it
+                (direction, curr_tile) = (next_d, next_tile);
+                distance += 1;
+                continue;
+            }
+            // next_steps.len() > 1 -- we're on a crossroads
+            if start_tile != enter_tile {
+                incidence_map
+                    .entry(curr_tile)
+                    .or_default()
+                    .push((start_tile, distance));
+            }
+            if !visited_crossroads.contains(&curr_tile) {
+                incidence_map
+                    .entry(start_tile)
+                    .or_default()
+                    .push((curr_tile, distance));
+                visited_crossroads.insert(curr_tile);
+                for (next_d, _) in next_steps {
+                    todo.push((curr_tile, next_d));
+                }
+            }
+            break;
+        }
+    }
+    incidence_map
+}
+
+/// The directions leading on from `state` that aren't blocked by a forest, with the positions
+/// they lead to.
+fn available_steps(grid: &Grid<u8>, state: SearchState) -> Vec<(Direction, Pos)> {
+    let (pos, d) = state;
+    [Right, Left, Straight]
+        .into_iter()
+        .map(|turn| d.after_turn(turn))
+        .filter_map(|d| Some((d, grid.offset(pos, d.delta())?)))
+        .filter(|&(_, pos)| grid[pos] != b'#')
+        .collect()
+}
@@ -0,0 +1,257 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    sync::OnceLock,
+};
+
+use regex::Regex;
+
+use crate::{
+    parse::{self, ParseError},
+    Fallible, Solution,
+};
+
+const DAY: u8 = 24;
+
+pub struct Day24 {
+    pub box_low: f64,
+    pub box_high: f64,
+}
+
+impl Default for Day24 {
+    fn default() -> Self {
+        Day24 {
+            box_low: 200_000_000_000_000.0,
+            box_high: 400_000_000_000_000.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct HailStone {
+    x: isize,
+    y: isize,
+    z: isize,
+    vx: isize,
+    vy: isize,
+    vz: isize,
+}
+
+impl Solution for Day24 {
+    type Input = Vec<HailStone>;
+    type Answer1 = usize;
+    type Answer2 = Fallible<isize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(DAY, input)
+            .map(|line| {
+                let caps = line.captures(hail_re())?;
+                let mut coords = [0_isize; 6];
+                for (i, coord) in coords.iter_mut().enumerate() {
+                    *coord = line.number(caps.get(i + 1).unwrap().as_str())?;
+                }
+                let [x, y, z, vx, vy, vz] = coords;
+                Ok(HailStone { x, y, z, vx, vy, vz })
+            })
+            .collect()
+    }
+
+    fn part1(&self, hail_stones: &Self::Input) -> usize {
+        // y - y1 = (x - x1) (vy1 / vx1)
+        // (y - y1) / vy1 = (x - x1) / vx1
+        //
+        // vx1 (y - y1) = vy1 (x - x1)   // :vx1
+        // vx2 (y - y2) = vy2 (x - x2)   // :vx2
+        //
+        // y - y1 = (vy1 / vx1) (x - x1)     /
+        // y - y2 = (vy2 / vx2) (x - x2)    / -
+        //
+        // y2 - y1 = (vy1 / vx1)(x - x1) - (vy2 / vx2)(x - x2)
+        // y2 - y1 = (vy1 / vx1) x - x1 vy1 / vx1 - (vy2 / vx2) x + x2 vy2 / vx2
+        // (vy1 / vx1 - vy2 / vx2) x - x1 vy1 / vx1 + x2 vy2 / vx2 + y1 - y2 = 0
+        //
+        // x = (x1 vy1 / vx1 - x2 vy2 / vx2 + y2 - y1) / (vy1 / vx1 - vy2 / vx2)
+        // x = (x1 vy1 / vx1 - x2 vy2 / vx2 + y2 - y1) * vx1 * vx2 / (vx2 vy1 - vx1 vy2)
+        //
+        // y = y1 + vy1 / vx1 * (x - x1)
+        // y = y1 + vy1 / vx1 * ((x1 vy1 / vx1 - x2 vy2 / vx2 + y2 - y1) * vx1 * vx2 / (vx2 vy1 - vx1 vy2) - x1)
+        // y = y1 - x1 vy1 / vx1 + vy1 vx2 (x1 vy1 / vx1 - x2 vy2 / vx2 + y2 - y1) / (vx2 vy1 - vx1 vy2)
+
+        let mut count = 0;
+        for (i, &HailStone { x: x1, y: y1, vx: vx1, vy: vy1, .. }) in hail_stones.iter().enumerate()
+        {
+            for &HailStone { x: x2, y: y2, vx: vx2, vy: vy2, .. } in hail_stones.iter().skip(i + 1)
+            {
+                let (x1, y1, vx1, vy1, x2, y2, vx2, vy2) = (
+                    x1 as f64, y1 as f64, vx1 as f64, vy1 as f64, x2 as f64, y2 as f64, vx2 as f64,
+                    vy2 as f64,
+                );
+                let x = (vx1 * vx2 * (y2 - y1) + vy1 * vx2 * x1 - vy2 * vx1 * x2)
+                    / (vy1 * vx2 - vy2 * vx1);
+                let y = y1 - x1 * vy1 / vx1
+                    + (vy1 * vx2 * (y2 - y1) + x1 * vy1 * vy1 * vx2 / vx1 - x2 * vy1 * vy2)
+                        / (vx2 * vy1 - vx1 * vy2);
+                let t1 = (x - x1) / vx1;
+                let t2 = (x - x2) / vx2;
+                if t1 > 0.0
+                    && t2 > 0.0
+                    && x >= self.box_low
+                    && x <= self.box_high
+                    && y >= self.box_low
+                    && y <= self.box_high
+                {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    fn part2(&self, hail_stones: &Self::Input) -> Fallible<isize> {
+        rock_position(hail_stones).into()
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "box_low" => self.box_low = parse::setting(name, value)?,
+            "box_high" => self.box_high = parse::setting(name, value)?,
+            _ => return Err(format!("Unknown setting: {name}")),
+        }
+        Ok(())
+    }
+}
+
+/// The sum of the coordinates the rock is thrown from. The rock's speed along each axis is
+/// found from hailstones moving at the same speed along it, then its position from the first
+/// four hailstones.
+fn rock_position(hail_stones: &[HailStone]) -> Result<isize, String> {
+    if hail_stones.len() < 4 {
+        return Err("Expected at least four hailstones".to_string());
+    }
+    let vrx = rock_speed(
+        hail_stones.to_vec(),
+        |&HailStone { vx, .. }| vx,
+        |&HailStone { x, .. }| x,
+    )
+    .ok_or_else(|| no_speed("x"))?;
+    let vry = rock_speed(
+        hail_stones.to_vec(),
+        |&HailStone { vy, .. }| vy,
+        |&HailStone { y, .. }| y,
+    )
+    .ok_or_else(|| no_speed("y"))?;
+    let vrz = rock_speed(
+        hail_stones.to_vec(),
+        |&HailStone { vz, .. }| vz,
+        |&HailStone { z, .. }| z,
+    )
+    .ok_or_else(|| no_speed("z"))?;
+
+    // (vy2 - vy1) xr + (vx1 - vx2) yr + (y1 - y2) vrx + (x2 - x1) vry = x2 vy2 - y2 vx2 - x1 vy1 + y1 vx1
+    // (vy2 - vy1) xr + (vx1 - vx2) yr = x2 vy2 - y2 vx2 - x1 vy1 + y1 vx1 + (y2 - y1) vrx - (x2 - x1) vry
+    //
+    // a1 = vy2 - vy1
+    // b1 = vx1 - vx2
+    // c1 = x2 vy2 - x1 vy1 + y1 vx1 - y2 vx2 + (y2 - y1) vrx - (x2 - x1) vry
+    //
+    // a1 xr + b1 yr = c1
+    // a2 xr + b2 yr = c2  // * a1/a2
+    // -------------------------------------------
+    // a1 xr + b1 yr = c1                     /
+    // a1 xr + a1 b2 yr / a2 = c2 a1 / a2    / -
+    // -------------------------------------------
+    // b1 yr - a1 b2 yr / a2 = c1 - c2 a1 / a2
+    // yr ( b1 - a1 b2 / a2 ) = c1 - c2 a1 / a2
+    // yr = ( c1 - c2 a1 / a2 ) / ( b1 - a1 b2 / a2 )
+    // yr = ( a2 c1 - a1 c2) / ( a2 b1 - a1 b2 )
+    // xr = (c1 - b1 yr) / a1
+
+    let HailStone { x: x1, y: y1, z: z1, vx: vx1, vy: vy1, vz: vz1 } = hail_stones[0];
+    let HailStone { x: x2, y: y2, z: z2, vx: vx2, vy: vy2, vz: vz2 } = hail_stones[1];
+    let HailStone { x: x3, y: y3, z: z3, vx: vx3, vy: vy3, vz: vz3 } = hail_stones[2];
+    let HailStone { x: x4, y: y4, z: z4, vx: vx4, vy: vy4, vz: vz4 } = hail_stones[3];
+
+    let a1 = vy2 - vy1;
+    let b1 = vx1 - vx2;
+    let c1 = x2 * vy2 - x1 * vy1 + y1 * vx1 - y2 * vx2 + (y2 - y1) * vrx - (x2 - x1) * vry;
+
+    let a2 = vy4 - vy3;
+    let b2 = vx3 - vx4;
+    let c2 = x4 * vy4 - x3 * vy3 + y3 * vx3 - y4 * vx4 + (y4 - y3) * vrx - (x4 - x3) * vry;
+
+    let yr = (a2 * c1 - a1 * c2)
+        .checked_div(a2 * b1 - a1 * b2)
+        .ok_or_else(undetermined)?;
+    let xr = (c1 - b1 * yr).checked_div(a1).ok_or_else(undetermined)?;
+
+    let a3 = vz2 - vz1;
+    let b3 = vx1 - vx2;
+    let c3 = x2 * vz2 - x1 * vz1 + z1 * vx1 - z2 * vx2 + (z2 - z1) * vrx - (x2 - x1) * vrz;
+
+    let a4 = vz4 - vz3;
+    let b4 = vx3 - vx4;
+    let c4 = x4 * vz4 - x3 * vz3 + z3 * vx3 - z4 * vx4 + (z4 - z3) * vrx - (x4 - x3) * vrz;
+
+    let zr = (a4 * c3 - a3 * c4)
+        .checked_div(a4 * b3 - a3 * b4)
+        .ok_or_else(undetermined)?;
+
+    Ok(xr + yr + zr)
+}
+
+fn no_speed(axis: &str) -> String {
+    format!("The hailstones with the same {axis} speed don't tell the rock's {axis} speed")
+}
+
+fn undetermined() -> String {
+    "The first four hailstones don't determine where the rock is thrown from".to_string()
+}
+
+fn rock_speed(
+    mut hail_stones: Vec<HailStone>,
+    speed_fn: impl Fn(&HailStone) -> isize,
+    position_fn: impl Fn(&HailStone) -> isize,
+) -> Option<isize> {
+    let mut stone_pairs = Vec::<(HailStone, HailStone)>::new();
+    hail_stones.sort_by_key(&speed_fn);
+    for i in 0..hail_stones.len() - 1 {
+        let stone1 = hail_stones[i];
+        let stone2 = hail_stones[i + 1];
+        if speed_fn(&stone1) == speed_fn(&stone2) {
+            stone_pairs.push((stone1, stone2));
+        }
+    }
+    stone_pairs
+        .into_iter()
+        .map(|(stone1, stone2)| {
+            let x1 = position_fn(&stone1);
+            let x2 = position_fn(&stone2);
+            let v = speed_fn(&stone1);
+            divisors(x1 - x2)
+                .into_iter()
+                .flat_map(|d| [v + d, v - d])
+                .collect::<BTreeSet<isize>>()
+        })
+        .reduce(|speeds1, speeds2| speeds1.intersection(&speeds2).cloned().collect())?
+        .into_iter()
+        .next()
+}
+
+fn divisors(n: isize) -> HashSet<isize> {
+    let n = n.abs();
+    let sqrt = (n as f64).sqrt().ceil() as isize;
+    let mut divisors = HashSet::<isize>::new();
+    for d in 1..sqrt {
+        if n % d == 0 {
+            divisors.insert(d);
+            divisors.insert(n / d);
+        }
+    }
+    divisors
+}
+
+fn hail_re() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r"^(\d+), *(\d+), *(\d+) *@ *(-?\d+), *(-?\d+), *(-?\d+)$").unwrap()
+    })
+}
@@ -0,0 +1,176 @@
+use std::{
+    cmp::Ordering::{Equal, Greater, Less},
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::{self, Write},
+};
+
+use priority_queue::PriorityQueue;
+
+use crate::{
+    parse::{self, Line, ParseError},
+    Fallible, Solution,
+};
+
+const DAY: u8 = 25;
+
+pub type Node = [u8; 3];
+pub type Edge = (Node, Node);
+
+#[derive(Default)]
+pub struct Day25 {
+    /// Show the edges of the minimum cut and the sizes of the two components after the answer.
+    pub show_cut: bool,
+}
+
+/// The fewest edges whose removal splits the graph in two, and the sizes of the two parts.
+pub struct Cut {
+    pub edges: Vec<Edge>,
+    pub sizes: (usize, usize),
+    show: bool,
+}
+
+impl fmt::Display for Cut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (size1, size2) = self.sizes;
+        write!(f, "{}", size1 * size2)?;
+        if self.show {
+            let edges: Vec<String> = self.edges.iter().map(|&e| edge_to_string(e)).collect();
+            write!(f, "\nCut: {}", edges.join(", "))?;
+            write!(f, "\nComponents: {size1} and {size2}")?;
+        }
+        Ok(())
+    }
+}
+
+fn node(line: &Line, name: &str) -> Result<Node, ParseError> {
+    match *name.as_bytes() {
+        [a, b, c] if name.bytes().all(|b| b.is_ascii_lowercase()) => Ok([a, b, c]),
+        _ => Err(line.error(name, "expected a three letter component name")),
+    }
+}
+
+/// The edge between two different nodes, with the nodes in order.
+fn edge(src: Node, dst: Node) -> Option<Edge> {
+    match src.cmp(&dst) {
+        Less => Some((src, dst)),
+        Greater => Some((dst, src)),
+        Equal => None,
+    }
+}
+
+impl Solution for Day25 {
+    type Input = HashMap<Node, BTreeSet<Edge>>;
+    type Answer1 = Fallible<Cut>;
+    type Answer2 = &'static str;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut graph = HashMap::<Node, BTreeSet<Edge>>::new();
+        for line in parse::lines(DAY, input) {
+            let (src_str, dests_str) = line.split_once(line.text, ": ")?;
+            let src = node(&line, src_str)?;
+            for dst_str in dests_str.split(" ") {
+                let edge = edge(src, node(&line, dst_str)?)
+                    .ok_or_else(|| line.error(dst_str, "expected another component"))?;
+                let (n1, n2) = edge;
+                graph.entry(n1).or_default().insert(edge);
+                graph.entry(n2).or_default().insert(edge);
+            }
+        }
+        Ok(graph)
+    }
+
+    fn part1(&self, graph: &Self::Input) -> Fallible<Cut> {
+        let cut = min_cut(graph)
+            .map(|cut| Cut { show: self.show_cut, ..cut })
+            .ok_or_else(|| "There are fewer than two components to cut apart".to_string());
+        cut.into()
+    }
+
+    /// There is no second puzzle on the last day.
+    fn part2(&self, _graph: &Self::Input) -> &'static str {
+        "Merry Christmas!"
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "show_cut" => self.show_cut = parse::setting(name, value)?,
+            _ => return Err(format!("Unknown setting: {name}")),
+        }
+        Ok(())
+    }
+
+    fn to_dot(&self, graph: &Self::Input) -> Option<String> {
+        let edges: BTreeSet<Edge> = graph.values().flatten().copied().collect();
+        let mut dot = String::from("graph components {\n");
+        for (n1, n2) in edges {
+            let (n1, n2) = (String::from_utf8_lossy(&n1), String::from_utf8_lossy(&n2));
+            writeln!(dot, "    \"{n1}\" -- \"{n2}\";").unwrap();
+        }
+        dot.push_str("}\n");
+        Some(dot)
+    }
+}
+
+/// Finds a minimum edge cut with the Stoer-Wagner algorithm. Every phase orders the remaining
+/// nodes by how strongly they're connected to the nodes before them; the last node is then cut
+/// off by the sum of its edge weights, and merged into the node before it. Returns `None` if
+/// the graph has fewer than two nodes.
+pub fn min_cut(graph: &HashMap<Node, BTreeSet<Edge>>) -> Option<Cut> {
+    let nodes: Vec<Node> = graph.keys().copied().collect();
+    let index: HashMap<Node, usize> = nodes.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    let mut weights: Vec<HashMap<usize, u32>> = nodes
+        .iter()
+        .map(|node| {
+            graph[node]
+                .iter()
+                .map(|&(n1, n2)| (index[if n1 == *node { &n2 } else { &n1 }], 1))
+                .collect()
+        })
+        .collect();
+    let mut merged: Vec<Vec<usize>> = (0..nodes.len()).map(|i| vec![i]).collect();
+    let mut remaining: Vec<usize> = (0..nodes.len()).collect();
+    let mut best: Option<(u32, Vec<usize>)> = None;
+    while remaining.len() > 1 {
+        let mut queue: PriorityQueue<usize, u32> = remaining.iter().map(|&i| (i, 0)).collect();
+        let (mut previous, mut last, mut cut_weight) = (remaining[0], remaining[0], 0);
+        while let Some((node, weight)) = queue.pop() {
+            (previous, last, cut_weight) = (last, node, weight);
+            for (&neighbor, &edge_weight) in &weights[node] {
+                queue.change_priority_by(&neighbor, |weight| *weight += edge_weight);
+            }
+        }
+        if best.as_ref().is_none_or(|(weight, _)| cut_weight < *weight) {
+            best = Some((cut_weight, merged[last].clone()));
+        }
+        for (neighbor, edge_weight) in std::mem::take(&mut weights[last]) {
+            weights[neighbor].remove(&last);
+            if neighbor != previous {
+                *weights[previous].entry(neighbor).or_default() += edge_weight;
+                *weights[neighbor].entry(previous).or_default() += edge_weight;
+            }
+        }
+        let last_merged = std::mem::take(&mut merged[last]);
+        merged[previous].extend(last_merged);
+        remaining.retain(|&i| i != last);
+    }
+    let side: HashSet<Node> = best?.1.into_iter().map(|i| nodes[i]).collect();
+    let edges = graph
+        .values()
+        .flatten()
+        .filter(|(n1, n2)| side.contains(n1) != side.contains(n2))
+        .copied()
+        .collect::<BTreeSet<Edge>>()
+        .into_iter()
+        .collect();
+    let sizes = (side.len(), nodes.len() - side.len());
+    Some(Cut { edges, sizes, show: false })
+}
+
+fn edge_to_string(e: Edge) -> String {
+    let (n1, n2) = e;
+    format!(
+        "{}/{}",
+        String::from_utf8_lossy(&n1),
+        String::from_utf8_lossy(&n2)
+    )
+}
@@ -0,0 +1,101 @@
+//! Directions on a grid where `y` grows downwards, matching [`crate::grid::Pos`].
+
+use std::fmt;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+use Direction::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Turn {
+    Left,
+    Straight,
+    Right,
+    Back,
+}
+
+impl Direction {
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction; 4] = [North, East, South, West];
+
+    pub fn opposite(self) -> Direction {
+        self.rotate_cw().rotate_cw()
+    }
+
+    pub fn rotate_cw(self) -> Direction {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn rotate_ccw(self) -> Direction {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn after_turn(self, turn: Turn) -> Direction {
+        match turn {
+            Turn::Left => self.rotate_ccw(),
+            Turn::Straight => self,
+            Turn::Right => self.rotate_cw(),
+            Turn::Back => self.opposite(),
+        }
+    }
+
+    /// The `(dy, dx)` step of one move in this direction.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            North => (-1, 0),
+            East => (0, 1),
+            South => (1, 0),
+            West => (0, -1),
+        }
+    }
+
+    /// Parses `U`/`R`/`D`/`L` (up, right, down, left) as well as `N`/`E`/`S`/`W`.
+    pub fn from_letter(letter: u8) -> Option<Direction> {
+        match letter {
+            b'U' | b'N' => Some(North),
+            b'R' | b'E' => Some(East),
+            b'D' | b'S' => Some(South),
+            b'L' | b'W' => Some(West),
+            _ => None,
+        }
+    }
+
+    /// Parses `^`, `>`, `v` and `<`.
+    pub fn from_arrow(arrow: u8) -> Option<Direction> {
+        Self::ALL
+            .into_iter()
+            .find(|direction| direction.to_arrow() == arrow)
+    }
+
+    pub fn to_arrow(self) -> u8 {
+        match self {
+            North => b'^',
+            East => b'>',
+            South => b'v',
+            West => b'<',
+        }
+    }
+
+    /// Parses the hex digits `0` to `3` as right, down, left and up, the encoding of the dig plan
+    /// colors in day 18.
+    pub fn from_hex_digit(digit: u8) -> Option<Direction> {
+        match digit {
+            b'0' => Some(East),
+            b'1' => Some(South),
+            b'2' => Some(West),
+            b'3' => Some(North),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_arrow() as char)
+    }
+}
@@ -0,0 +1,206 @@
+//! A rectangular grid of cells, the shape of most of the puzzle inputs.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{parse, ParseError};
+
+/// A position in a grid as `(y, x)`, row first.
+pub type Pos = (usize, usize);
+
+/// `(dy, dx)` steps to the four orthogonal neighbors: up, right, down, left.
+pub const NEIGHBORS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// `(dy, dx)` steps to the eight neighbors including the diagonal ones, clockwise from up.
+pub const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "rows differ in length"
+        );
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (y, x): Pos) -> Option<&T> {
+        (y < self.height && x < self.width).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (y, x): Pos) -> Option<&mut T> {
+        (y < self.height && x < self.width).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// The position `(dy, dx)` away from `pos`, if it's inside the grid.
+    pub fn offset(&self, (y, x): Pos, (dy, dx): (isize, isize)) -> Option<Pos> {
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        Some((y, x))
+    }
+
+    /// The orthogonal neighbors of `pos` that are inside the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS4
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos` that are inside the grid.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS8
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (y, x)))
+    }
+
+    /// All positions with their cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside of the grid");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        let cells = self.cells.iter().map(f).collect();
+        Grid { width: self.width, height: self.height, cells }
+    }
+
+    /// Mirrors the grid along its main diagonal, so that rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = self.columns().flat_map(|column| column.cloned()).collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).rev().cloned())
+            .collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+}
+
+impl Grid<u8> {
+    /// Parses the lines of `input` as the rows of a grid, which must have the same length and
+    /// consist of the bytes in `allowed`. Errors are reported for the puzzle of `day`.
+    pub fn parse(day: u8, input: &str, allowed: &[u8]) -> Result<Self, ParseError> {
+        parse::grid(day, input, allowed)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+/// Shows every cell as a character, one row per line.
+impl<T: Copy + Into<char>> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            let line: String = row.iter().map(|&cell| cell.into()).collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+//! Solutions to the Advent of Code 2023 puzzles.
+//!
+//! Every `dayNN` module exposes a `DayNN` type implementing [`Solution`], so the solvers can be
+//! driven from any program, not just from the command line.
+
+use std::{
+    any::Any,
+    fmt::{self, Display},
+};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod direction;
+pub mod grid;
+pub mod math;
+mod parse;
+pub mod search;
+
+pub use parse::ParseError;
+
+/// A solver for one day's puzzle.
+///
+/// `parse` turns the raw puzzle input into a representation that both parts work from, so the
+/// input is only parsed once when solving both parts. Malformed input is reported by `parse`,
+/// the parts expect input that `parse` accepted. Parts that can only find out while solving
+/// that an input has no answer return a [`Fallible`] answer.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(&self, input: &Self::Input) -> Self::Answer1;
+
+    fn part2(&self, input: &Self::Input) -> Self::Answer2;
+
+    /// Changes one of the solver's settings, like the number of steps of day 21 part 1.
+    fn set(&mut self, name: &str, _value: &str) -> Result<(), String> {
+        Err(format!("Unknown setting: {name}"))
+    }
+
+    /// The parsed input as a Graphviz DOT graph, for the days whose input describes a graph.
+    fn to_dot(&self, _input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// Object-safe counterpart of [`Solution`], for picking a solver by day number at runtime.
+/// The parsed input is passed around type-erased and the answers are rendered as strings.
+pub trait Solver {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    fn part1(&self, input: &dyn Any) -> String;
+
+    fn part2(&self, input: &dyn Any) -> String;
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+
+    fn to_dot(&self, input: &dyn Any) -> Option<String>;
+}
+
+impl<S: Solution> Solver for S
+where
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> String {
+        Solution::part1(self, downcast_input::<S>(input)).to_string()
+    }
+
+    fn part2(&self, input: &dyn Any) -> String {
+        Solution::part2(self, downcast_input::<S>(input)).to_string()
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        Solution::set(self, name, value)
+    }
+
+    fn to_dot(&self, input: &dyn Any) -> Option<String> {
+        Solution::to_dot(self, downcast_input::<S>(input))
+    }
+}
+
+fn downcast_input<S: Solution>(input: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("input wasn't produced by this solver's parse")
+}
+
+/// The answer of a part, or why an input that `parse` accepted has none, like one that breaks
+/// the assumptions the part relies on.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Fallible<T>(pub Result<T, String>);
+
+impl<T: Display> Display for Fallible<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Ok(answer) => answer.fmt(f),
+            Err(message) => write!(f, "no answer: {message}"),
+        }
+    }
+}
+
+impl<T> From<Result<T, String>> for Fallible<T> {
+    fn from(result: Result<T, String>) -> Self {
+        Fallible(result)
+    }
+}
+
+/// Returns the solver for the given day of the month, with its default settings.
+pub fn solver(day: u8) -> Option<Box<dyn Solver>> {
+    let solver: Box<dyn Solver> = match day {
+        1 => Box::new(day01::Day01::default()),
+        2 => Box::new(day02::Day02::default()),
+        3 => Box::new(day03::Day03::default()),
+        4 => Box::new(day04::Day04::default()),
+        5 => Box::new(day05::Day05::default()),
+        6 => Box::new(day06::Day06),
+        7 => Box::new(day07::Day07::default()),
+        8 => Box::new(day08::Day08),
+        9 => Box::new(day09::Day09::default()),
+        10 => Box::new(day10::Day10::default()),
+        11 => Box::new(day11::Day11),
+        12 => Box::new(day12::Day12),
+        13 => Box::new(day13::Day13),
+        14 => Box::new(day14::Day14),
+        15 => Box::new(day15::Day15),
+        16 => Box::new(day16::Day16),
+        17 => Box::new(day17::Day17::default()),
+        18 => Box::new(day18::Day18),
+        19 => Box::new(day19::Day19),
+        20 => Box::new(day20::Day20::default()),
+        21 => Box::new(day21::Day21::default()),
+        22 => Box::new(day22::Day22),
+        23 => Box::new(day23::Day23),
+        24 => Box::new(day24::Day24::default()),
+        25 => Box::new(day25::Day25::default()),
+        _ => return None,
+    };
+    Some(solver)
+}
@@ -0,0 +1,52 @@
+//! Number theory helpers shared by the days that combine cycles.
+
+/// The greatest common divisor, which is `0` only if both numbers are.
+pub fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple. Panics if it overflows.
+pub fn lcm(a: usize, b: usize) -> usize {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b)).checked_mul(b).expect("lcm overflow")
+}
+
+/// Solves `x ≡ r1 (mod m1)` and `x ≡ r2 (mod m2)` together, for moduli that don't need to be
+/// coprime. Returns the solution as `x ≡ r (mod lcm(m1, m2))`, or `None` if there is none.
+pub fn crt((r1, m1): (i128, i128), (r2, m2): (i128, i128)) -> Option<(i128, i128)> {
+    let (g, p, _) = extended_gcd(m1, m2);
+    if (r2 - r1) % g != 0 {
+        return None;
+    }
+    let lcm = m1 / g * m2;
+    // p * m1 ≡ g (mod m2), so stepping (r2 - r1) / g * p times m1 from r1 reaches r2 mod m2
+    let steps = ((r2 - r1) / g % (m2 / g)) * p % (m2 / g);
+    Some(((r1 + steps * m1).rem_euclid(lcm), lcm))
+}
+
+/// Returns `(gcd(a, b), p, q)` with `p * a + q * b = gcd(a, b)`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+    let (g, p, q) = extended_gcd(b, a % b);
+    (g, q, p - a / b * q)
+}
+
+/// The binomial coefficient `n choose k` for any integer `n`, as the polynomial
+/// `n (n - 1) ... (n - k + 1) / k!`. Returns `None` if it overflows.
+pub fn binomial(n: i128, k: usize) -> Option<i128> {
+    let mut result: i128 = 1;
+    for i in 0..k as i128 {
+        // result * (n - i) is divisible by i + 1, so dividing first keeps the numbers small
+        let g = extended_gcd(result.abs(), i + 1).0;
+        let factor = n.checked_sub(i)? / ((i + 1) / g);
+        result = (result / g).checked_mul(factor)?;
+    }
+    Some(result)
+}
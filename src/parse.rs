@@ -0,0 +1,171 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use regex::{Captures, Regex};
+
+use crate::grid::Grid;
+
+/// Describes where and why a puzzle input couldn't be parsed. Lines and columns count from 1,
+/// `text` is the offending part of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ParseError { day, line, column, text, message } = self;
+        write!(
+            f,
+            "Day {day}, line {line}, column {column}: {message}: {text:?}"
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of puzzle input that remembers where it came from, so that errors can point at it.
+#[derive(Clone, Copy)]
+pub(crate) struct Line<'a> {
+    pub day: u8,
+    pub number: usize,
+    pub text: &'a str,
+}
+
+pub(crate) fn lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(move |(i, text)| Line { day, number: i + 1, text })
+}
+
+/// Reports input that ends before the puzzle description does.
+pub(crate) fn end_of_input(day: u8, input: &str, message: &str) -> ParseError {
+    ParseError {
+        day,
+        line: input.lines().count() + 1,
+        column: 1,
+        text: String::new(),
+        message: message.to_string(),
+    }
+}
+
+impl<'a> Line<'a> {
+    /// Creates an error pointing at `offending`, which should be a slice of this line. For
+    /// anything else the error points at the start of the line.
+    pub fn error(&self, offending: &str, message: impl Into<String>) -> ParseError {
+        let offset = (offending.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        let column = if offset <= self.text.len() {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        };
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column,
+            text: offending.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn split_once(
+        &self,
+        text: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(delimiter)
+            .ok_or_else(|| self.error(text, format!("expected {delimiter:?}")))
+    }
+
+    pub fn number<T>(&self, field: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        field
+            .parse()
+            .map_err(|e| self.error(field, format!("expected a number ({e})")))
+    }
+
+    pub fn numbers<T>(&self, text: &str) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        text.split_whitespace()
+            .map(|field| self.number(field))
+            .collect()
+    }
+
+    pub fn captures(&self, re: &Regex) -> Result<Captures<'a>, ParseError> {
+        re.captures(self.text)
+            .ok_or_else(|| self.error(self.text, format!("expected a match for {re}")))
+    }
+}
+
+/// Parses the value of a solver setting.
+pub(crate) fn setting<T>(name: &str, value: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    value
+        .parse()
+        .map_err(|e| format!("Invalid value for {name}: {value:?} ({e})"))
+}
+
+/// Splits the input into blocks of lines separated by empty lines.
+pub(crate) fn blocks(day: u8, input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![Vec::new()];
+    for line in lines(day, input) {
+        if line.text.is_empty() {
+            blocks.push(Vec::new());
+        } else {
+            blocks.last_mut().unwrap().push(line);
+        }
+    }
+    blocks.retain(|block| !block.is_empty());
+    blocks
+}
+
+/// Parses a rectangular grid of bytes, each of which must be one of `allowed`.
+pub(crate) fn grid(day: u8, input: &str, allowed: &[u8]) -> Result<Grid<u8>, ParseError> {
+    let grid_lines: Vec<Line> = lines(day, input).collect();
+    let grid = grid_rows(&grid_lines, allowed)?;
+    if grid.width() == 0 || grid.height() == 0 {
+        return Err(end_of_input(day, input, "expected a grid"));
+    }
+    Ok(grid)
+}
+
+/// Like [`grid`], for a grid that is only part of the input.
+pub(crate) fn grid_rows(grid_lines: &[Line], allowed: &[u8]) -> Result<Grid<u8>, ParseError> {
+    let mut grid: Vec<Vec<u8>> = Vec::new();
+    for line in grid_lines {
+        if let Some(first_row) = grid.first() {
+            if line.text.len() != first_row.len() {
+                return Err(line.error(
+                    line.text,
+                    format!("expected a row of length {}", first_row.len()),
+                ));
+            }
+        }
+        if let Some((i, _)) = line
+            .text
+            .char_indices()
+            .find(|&(_, ch)| !ch.is_ascii() || !allowed.contains(&(ch as u8)))
+        {
+            let ch_len = line.text[i..].chars().next().unwrap().len_utf8();
+            return Err(line.error(
+                &line.text[i..i + ch_len],
+                format!("expected one of {:?}", String::from_utf8_lossy(allowed)),
+            ));
+        }
+        grid.push(line.text.as_bytes().to_vec());
+    }
+    Ok(Grid::from_rows(grid))
+}
@@ -0,0 +1,184 @@
+//! Shortest path searches over implicit graphs, where a closure lists the neighbors of a node.
+
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use priority_queue::PriorityQueue;
+
+/// What a search found out: the distance of every reached node from the nearest start, and how
+/// to get there.
+pub struct Paths<N, C> {
+    distances: HashMap<N, C>,
+    predecessors: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    /// The goal node the search stopped at, if it found one.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// The distance of the goal node the search stopped at.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// The nodes of a shortest path from a start node to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(predecessor) = self.predecessors.get(path.last().unwrap()) {
+            path.push(predecessor.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Finds the cheapest path from any of the `starts` to a node for which `is_goal` holds.
+/// `neighbors` lists the nodes reachable in one step, with the non-negative cost of that step.
+/// Without a reachable goal, all reachable nodes are explored.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but visits the nodes in the order of their distance plus `heuristic`, an
+/// estimate of the remaining cost to the goal. The estimate must never exceed the actual cost.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+        goal: None,
+    };
+    let mut unvisited: PriorityQueue<N, Reverse<C>> = PriorityQueue::new();
+    for start in starts {
+        paths.distances.insert(start.clone(), C::default());
+        let estimate = heuristic(&start);
+        unvisited.push(start, Reverse(estimate));
+    }
+    while let Some((node, _)) = unvisited.pop() {
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+        let distance = paths.distances[&node];
+        for (neighbor, cost) in neighbors(&node) {
+            let neighbor_distance = distance + cost;
+            if paths
+                .distances
+                .get(&neighbor)
+                .is_some_and(|&known| known <= neighbor_distance)
+            {
+                continue;
+            }
+            paths.distances.insert(neighbor.clone(), neighbor_distance);
+            paths.predecessors.insert(neighbor.clone(), node.clone());
+            let estimate = neighbor_distance + heuristic(&neighbor);
+            unvisited.push_increase(neighbor, Reverse(estimate));
+        }
+    }
+    paths
+}
+
+/// Finds the path with the fewest steps from any of the `starts` to a node for which `is_goal`
+/// holds. Without a reachable goal, all reachable nodes are explored.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bounded_bfs(starts, neighbors, is_goal, usize::MAX)
+}
+
+/// Explores all nodes at most `max_steps` steps away from any of the `starts`, which is how a
+/// search over an infinite graph ends.
+pub fn bfs_within<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    max_steps: usize,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bounded_bfs(starts, neighbors, |_| false, max_steps)
+}
+
+fn bounded_bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+    max_steps: usize,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+        goal: None,
+    };
+    let mut todo = VecDeque::new();
+    for start in starts {
+        paths.distances.insert(start.clone(), 0);
+        todo.push_back(start);
+    }
+    while let Some(node) = todo.pop_front() {
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+        let distance = paths.distances[&node];
+        if distance == max_steps {
+            continue;
+        }
+        for neighbor in neighbors(&node) {
+            if paths.distances.contains_key(&neighbor) {
+                continue;
+            }
+            paths.distances.insert(neighbor.clone(), distance + 1);
+            paths.predecessors.insert(neighbor.clone(), node.clone());
+            todo.push_back(neighbor);
+        }
+    }
+    paths
+}
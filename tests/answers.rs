@@ -0,0 +1,165 @@
+//! Pins the answer of every day, so that refactoring a solver can't silently change its results.
+//!
+//! The examples from the puzzle texts live in `tests/examples/` and are always checked. The real
+//! puzzle inputs are personal and not part of the repository: put them in `input/dayNN.txt` and
+//! the answers in `REAL_ANSWERS` are checked too.
+
+use std::{fs, path::Path};
+
+use aoc2023::{solver, Solver};
+
+struct Case {
+    day: u8,
+    path: String,
+    solver: Box<dyn Solver>,
+    part1: Option<&'static str>,
+    part2: Option<&'static str>,
+}
+
+impl Case {
+    fn new(
+        day: u8,
+        path: String,
+        part1: Option<&'static str>,
+        part2: Option<&'static str>,
+    ) -> Self {
+        let solver = solver(day).unwrap();
+        Case {
+            day,
+            path,
+            solver,
+            part1,
+            part2,
+        }
+    }
+
+    fn example(
+        day: u8,
+        name: &str,
+        part1: Option<&'static str>,
+        part2: Option<&'static str>,
+    ) -> Self {
+        Case::new(day, format!("tests/examples/{name}.txt"), part1, part2)
+    }
+
+    /// Changes settings of the solver, like `--set` does for the runner.
+    fn with_settings(mut self, settings: &[(&str, &str)]) -> Self {
+        for (name, value) in settings {
+            self.solver.set(name, value).unwrap();
+        }
+        self
+    }
+
+    /// Returns a description of every answer that differs from the expected one.
+    fn check(&self, input: &str) -> Vec<String> {
+        let parsed = match self.solver.parse(input) {
+            Ok(parsed) => parsed,
+            Err(e) => return vec![format!("{}: {e}", self.path)],
+        };
+        let mut failures = Vec::new();
+        let answers = [
+            (
+                1,
+                self.part1
+                    .map(|expected| (expected, self.solver.part1(&*parsed))),
+            ),
+            (
+                2,
+                self.part2
+                    .map(|expected| (expected, self.solver.part2(&*parsed))),
+            ),
+        ];
+        for (part, answer) in answers {
+            if let Some((expected, actual)) = answer {
+                if actual != expected {
+                    failures.push(format!(
+                        "{} (day {}) part {part}: expected {expected}, got {actual}",
+                        self.path, self.day
+                    ));
+                }
+            }
+        }
+        failures
+    }
+}
+
+fn examples() -> Vec<Case> {
+    vec![
+        Case::example(1, "day01a", Some("142"), Some("142")),
+        Case::example(1, "day01", None, Some("281")),
+        Case::example(2, "day02", Some("8"), Some("2286")),
+        Case::example(3, "day03", Some("4361"), Some("467835")),
+        Case::example(4, "day04", Some("13"), Some("30")),
+        Case::example(5, "day05", Some("35"), Some("46")),
+        Case::example(6, "day06", Some("288"), Some("71503")),
+        Case::example(7, "day07", Some("6440"), Some("5905")),
+        Case::example(8, "day08", Some("2"), None),
+        Case::example(8, "day08a", Some("6"), None),
+        Case::example(8, "day08b", None, Some("6")),
+        Case::example(9, "day09", Some("114"), Some("2")),
+        Case::example(10, "day10", Some("8"), None),
+        Case::example(10, "day10a", None, Some("10")),
+        Case::example(11, "day11", Some("374"), Some("82000210")),
+        Case::example(12, "day12", Some("21"), Some("525152")),
+        Case::example(13, "day13", Some("405"), Some("400")),
+        Case::example(14, "day14", Some("136"), Some("64")),
+        Case::example(15, "day15", Some("1320"), Some("145")),
+        Case::example(16, "day16", Some("46"), Some("51")),
+        Case::example(17, "day17", Some("102"), Some("94")),
+        Case::example(18, "day18", Some("62"), Some("952408144115")),
+        Case::example(19, "day19", Some("19114"), Some("167409079868000")),
+        Case::example(20, "day20", Some("32000000"), None),
+        Case::example(20, "day20a", Some("11687500"), None),
+        Case::example(21, "day21", Some("16"), None).with_settings(&[("steps", "6")]),
+        Case::example(22, "day22", Some("5"), Some("7")),
+        Case::example(23, "day23", Some("94"), Some("154")),
+        Case::example(24, "day24", Some("2"), None)
+            .with_settings(&[("box_low", "7"), ("box_high", "27")]),
+        Case::example(25, "day25", Some("54"), None),
+    ]
+}
+
+/// Answers for the real puzzle inputs, as far as they are known.
+const REAL_ANSWERS: &[(u8, Option<&str>, Option<&str>)] = &[
+    (12, Some("7506"), Some("548241300348335")),
+    (13, Some("37975"), Some("32497")),
+    (14, Some("106517"), Some("79723")),
+    (15, Some("498538"), Some("286278")),
+    (16, Some("8021"), Some("8216")),
+    (17, Some("1263"), Some("1411")),
+    (18, None, Some("42708339569950")),
+    (19, Some("346230"), Some("124693661917133")),
+    (21, Some("3820"), Some("632421652138917")),
+    (22, Some("482"), Some("103010")),
+    (23, Some("2298"), None),
+    (24, None, Some("600352360036779")),
+];
+
+fn assert_no_failures(failures: Vec<String>) {
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn example_answers() {
+    let mut failures = Vec::new();
+    for case in examples() {
+        let input = fs::read_to_string(&case.path).unwrap();
+        failures.extend(case.check(&input));
+    }
+    assert_no_failures(failures);
+}
+
+#[test]
+fn real_answers() {
+    let mut failures = Vec::new();
+    for &(day, part1, part2) in REAL_ANSWERS {
+        let case = Case::new(day, format!("input/day{day:02}.txt"), part1, part2);
+        if !Path::new(&case.path).exists() {
+            eprintln!("Skipping day {day}, {} not found", case.path);
+            continue;
+        }
+        let input = fs::read_to_string(&case.path).unwrap();
+        failures.extend(case.check(&input));
+    }
+    assert_no_failures(failures);
+}
@@ -0,0 +1,35 @@
+use std::fs::read_to_string;
+
+use aoc2023::{
+    day01::{Calibrator, Day01},
+    Solution,
+};
+
+#[test]
+fn overlapping_words() {
+    let calibrator = Calibrator::new(&Day01::default().vocabulary);
+    assert_eq!(
+        calibrator.digits("xtwone3eightwo"),
+        [(1, 2), (3, 1), (6, 3), (7, 8), (11, 2)]
+    );
+    assert_eq!(calibrator.value("xtwone3eightwo"), Some(22));
+    assert_eq!(calibrator.value("abc"), None);
+}
+
+#[test]
+fn custom_vocabulary() {
+    let vocabulary = [("zero".to_string(), 0), ("ten".to_string(), 10)];
+    let calibrator = Calibrator::new(&vocabulary);
+    assert_eq!(calibrator.digits("zerone5ten"), [(0, 0), (6, 5), (7, 10)]);
+}
+
+#[test]
+fn spelled_out_digits_only() {
+    let input = read_to_string("tests/examples/day01.txt").unwrap();
+    let lines = Day01::default().parse(&input).unwrap();
+    assert_eq!(
+        Day01::default().part1(&lines).to_string(),
+        "no answer: Line 2 has no digit"
+    );
+    assert_eq!(Day01::default().part2(&lines), 281);
+}
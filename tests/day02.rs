@@ -0,0 +1,51 @@
+use std::fs::read_to_string;
+
+use aoc2023::{
+    day02::{exact_bag, Bag, Day02, Game},
+    Solution,
+};
+
+fn games() -> Vec<Game> {
+    let input = read_to_string("tests/examples/day02.txt").unwrap();
+    Day02::default().parse(&input).unwrap()
+}
+
+fn bag(cubes: &[(&str, usize)]) -> Bag {
+    cubes
+        .iter()
+        .map(|&(color, count)| (color.to_string(), count))
+        .collect()
+}
+
+#[test]
+fn minimum_bags() {
+    let games = games();
+    assert_eq!(
+        games[0].minimum_bag(),
+        bag(&[("red", 4), ("green", 2), ("blue", 6)])
+    );
+    assert!(games[0].is_possible_with(&games[0].minimum_bag()));
+    assert!(!games[2].is_possible_with(&Day02::default().bag));
+}
+
+#[test]
+fn bags_for_exactly_some_games() {
+    let games = games();
+    assert_eq!(
+        exact_bag(&games, &[1, 2, 5]),
+        Some(bag(&[("red", 6), ("green", 3), ("blue", 6)]))
+    );
+    // Any bag for game 3 has enough cubes for game 2 as well
+    assert_eq!(exact_bag(&games, &[1, 3]), None);
+}
+
+#[test]
+fn power_ignores_the_bag() {
+    let games = games();
+    assert_eq!(games[0].power(), 48);
+    let mut day02 = Day02::default();
+    assert_eq!(day02.part2(&games), 2286);
+    day02.set("bag", "1 blue").unwrap();
+    assert_eq!(day02.part2(&games), 2286);
+    assert_eq!(day02.part1(&games), 0);
+}
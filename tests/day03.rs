@@ -0,0 +1,55 @@
+use std::fs::read_to_string;
+
+use aoc2023::{
+    day03::{Day03, GearRule, Schematic, Span, Symbol},
+    Solution,
+};
+
+fn schematic() -> Schematic {
+    let input = read_to_string("tests/examples/day03.txt").unwrap();
+    Day03::default().parse(&input).unwrap()
+}
+
+#[test]
+fn adjacency() {
+    let schematic = schematic();
+    assert_eq!(schematic.numbers().len(), 10);
+    assert_eq!(schematic.numbers()[0].value, 467);
+    assert_eq!(schematic.numbers()[0].span, Span { y: 0, x: 0..3 });
+    let symbols: Vec<&Symbol> = schematic.symbols_next_to(0).collect();
+    assert_eq!(
+        symbols,
+        [&Symbol {
+            char: b'*',
+            pos: (1, 3)
+        }]
+    );
+    // 114 and 58 aren't next to a symbol
+    assert_eq!(schematic.symbols_next_to(1).count(), 0);
+    let values: Vec<usize> = schematic.numbers_next_to(0).map(|n| n.value).collect();
+    assert_eq!(values, [467, 35]);
+    assert_eq!(schematic.part_numbers().count(), 8);
+}
+
+#[test]
+fn gear_rules() {
+    let schematic = schematic();
+    let ratios: Vec<usize> = schematic.gear_ratios(&GearRule::default()).collect();
+    assert_eq!(ratios, [16345, 451490]);
+    let lonely = GearRule {
+        symbols: b"*#+$".to_vec(),
+        neighbor_count: 1,
+        combine: |values| values[0],
+    };
+    let ratios: Vec<usize> = schematic.gear_ratios(&lonely).collect();
+    assert_eq!(ratios, [633, 617, 592, 664]);
+}
+
+#[test]
+fn number_too_large() {
+    let input = format!("{}\n*.1234567890123456789012345.", ".".repeat(28));
+    let e = Day03::default().parse(&input).err().unwrap();
+    assert_eq!((e.line, e.column), (2, 3));
+    assert_eq!(e.text, "1234567890123456789012345");
+    assert_eq!(e.message, "expected a number that fits into a usize");
+}
@@ -0,0 +1,54 @@
+use std::fs::read_to_string;
+
+use aoc2023::{
+    day04::{copy_counts, Card, Day04},
+    Solution,
+};
+
+fn cards(input: &str) -> Vec<Card> {
+    Day04::default().parse(input).unwrap()
+}
+
+#[test]
+fn copy_cascade() {
+    let cards = cards(&read_to_string("tests/examples/day04.txt").unwrap());
+    let matches: Vec<usize> = cards.iter().map(Card::matches).collect();
+    assert_eq!(matches, [4, 2, 2, 1, 0, 0]);
+    assert_eq!(copy_counts(&cards), [1, 2, 4, 8, 14, 1]);
+}
+
+#[test]
+fn any_spacing_and_width() {
+    let cards = cards("Card   7:  100 5\t 2000 |1 2000 100    7\nCard 8: | 3");
+    assert_eq!(cards.iter().map(|card| card.id).collect::<Vec<_>>(), [7, 8]);
+    assert_eq!(cards[0].matches(), 2);
+    assert_eq!(cards[1].matches(), 0);
+    // The last card's copies can't win cards past the end
+    assert_eq!(copy_counts(&cards), [1, 2]);
+    assert!(Day04::default().parse("Card 1: 1 1 | 2").is_err());
+    assert!(Day04::default().parse("Cards: 1 | 2").is_err());
+}
+
+#[test]
+fn too_many_points() {
+    let card = |matches: usize| {
+        let numbers: Vec<String> = (1..=matches).map(|n| n.to_string()).collect();
+        let numbers = numbers.join(" ");
+        format!("Card 1: {numbers} | {numbers}")
+    };
+    let day04 = Day04::default();
+    let cards = day04.parse(&card(64)).unwrap();
+    assert_eq!(cards[0].points(), Some(1 << 63));
+    assert_eq!(day04.part1(&cards).to_string(), (1usize << 63).to_string());
+    let cards = day04.parse(&card(65)).unwrap();
+    assert_eq!(cards[0].points(), None);
+    assert_eq!(
+        day04.part1(&cards).to_string(),
+        "no answer: The points of card 1 don't fit into a usize"
+    );
+    let cards = day04.parse(&[card(64), card(64)].join("\n")).unwrap();
+    assert_eq!(
+        day04.part1(&cards).to_string(),
+        "no answer: The sum of the points doesn't fit into a usize"
+    );
+}
@@ -0,0 +1,83 @@
+use std::fs::read_to_string;
+
+use aoc2023::{
+    day05::{Almanac, Day05},
+    Solution,
+};
+
+fn almanac() -> Almanac {
+    let input = read_to_string("tests/examples/day05.txt").unwrap();
+    Day05::default().parse(&input).unwrap()
+}
+
+#[test]
+fn composed_chain_maps_like_the_steps() {
+    let almanac = almanac();
+    let composed = almanac.chain("seed", "location").unwrap();
+    assert_eq!(composed.name(), "seed-to-location");
+    for seed in 0..120 {
+        let stepwise = almanac
+            .mappings()
+            .iter()
+            .fold(seed, |id, mapping| mapping.map_src_to_dest(id));
+        assert_eq!(composed.map_src_to_dest(seed), stepwise, "seed {seed}");
+    }
+}
+
+#[test]
+fn inverse_finds_the_seed() {
+    let almanac = almanac();
+    let soil = almanac.mapping("seed-to-soil").unwrap();
+    assert_eq!(soil.map_src_to_dest(79), 81);
+    assert_eq!(soil.inverse().unwrap().map_src_to_dest(81), 79);
+
+    let seeds = almanac.chain("location", "seed").unwrap();
+    assert_eq!(seeds.name(), "location-to-seed");
+    assert_eq!(seeds.map_src_to_dest(46), 82);
+    assert!(almanac.mapping("seed-to-location").is_none());
+}
+
+#[test]
+fn ranges_and_printing() {
+    let almanac = almanac();
+    let soil = almanac.mapping("seed-to-soil").unwrap();
+    assert_eq!(soil.map_range(79..93), vec![81..95]);
+    assert_eq!(soil.map_range(45..100), [45..50, 52..100, 50..52]);
+    assert_eq!(
+        soil.to_string(),
+        "seed-to-soil:\n0 <= x < 50: x\n50 <= x < 98: x + 2\n98 <= x < 100: x - 48\nx >= 100: x"
+    );
+}
+
+#[test]
+fn seed_pairs() {
+    for seeds in ["seeds: 5", "seeds: 5 0", "seeds: 5 3 7"] {
+        assert!(Day05::default().parse(seeds).is_err(), "{seeds}");
+    }
+    assert!(Day05::default().parse("seeds: 5 3").is_ok());
+}
+
+#[test]
+fn ranges_past_the_end_of_i64() {
+    let error = |input: &str| {
+        let e = Day05::default().parse(input).err().unwrap();
+        (e.line, e.column, e.text, e.message)
+    };
+    let max = i64::MAX;
+    assert_eq!(
+        error(&format!("seeds: 1 2 {} 8", max - 7)),
+        (
+            1,
+            32,
+            "8".to_string(),
+            "expected a range that ends within an i64".to_string()
+        )
+    );
+    assert!(Day05::default()
+        .parse(&format!("seeds: {} 7", max - 7))
+        .is_ok());
+    let map = |line: &str| format!("seeds: 1 2\n\na-to-b map:\n{line}");
+    assert_eq!(error(&map(&format!("0 {max} 1"))).0, 4);
+    assert_eq!(error(&map(&format!("{max} 0 1"))).2, "1");
+    assert_eq!(error(&map("5 -3 2")).3, "expected a non-negative number");
+}
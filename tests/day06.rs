@@ -0,0 +1,31 @@
+use aoc2023::day06::num_choices;
+
+fn brute_force(race_time: u64, record_distance: u64) -> u64 {
+    (0..=race_time)
+        .filter(|charge| charge * (race_time - charge) > record_distance)
+        .count() as u64
+}
+
+#[test]
+fn agrees_with_brute_force_on_small_races() {
+    for race_time in 0..80 {
+        for record_distance in 0..=race_time * race_time / 4 + 2 {
+            assert_eq!(
+                num_choices(race_time, record_distance),
+                brute_force(race_time, record_distance),
+                "race time {race_time}, record distance {record_distance}"
+            );
+        }
+    }
+}
+
+#[test]
+fn exact_beyond_f64_precision() {
+    // Only charging for half of the race beats a record just short of its distance
+    let race_time = 2 * (1_u64 << 31) + 2;
+    let half = race_time as u128 / 2;
+    let record = (half * half - 1) as u64;
+    assert_eq!(num_choices(race_time, record), 1);
+    assert_eq!(num_choices(race_time, record + 1), 0);
+    assert_eq!(num_choices(u64::MAX, 0), u64::MAX - 1);
+}
@@ -0,0 +1,65 @@
+use aoc2023::{
+    day07::{classify, score, Day07, Hand, Ruleset, TieBreak},
+    Solution,
+};
+
+fn hands(hands: &[(&str, usize)]) -> Vec<Hand> {
+    hands
+        .iter()
+        .map(|&(cards, bid)| Hand {
+            cards: cards.bytes().collect(),
+            bid,
+        })
+        .collect()
+}
+
+#[test]
+fn wildcards_join_the_biggest_group() {
+    let rules = Ruleset {
+        wildcards: b"JQ".to_vec(),
+        ..Ruleset::jokers()
+    };
+    assert_eq!(classify(b"KTJJT", &rules), Some(vec![4, 1]));
+    assert_eq!(classify(b"QJ2J3", &rules), Some(vec![4, 1]));
+    assert_eq!(classify(b"JJJJQ", &rules), Some(vec![5]));
+    assert_eq!(
+        classify(b"T55J5", &Ruleset::camel_cards()),
+        Some(vec![3, 1, 1])
+    );
+}
+
+#[test]
+fn other_hand_sizes_and_group_rank() {
+    let rules = Ruleset {
+        hand_size: 3,
+        tie_break: TieBreak::GroupRank,
+        ..Ruleset::camel_cards()
+    };
+    // A pair of kings beats a pair of twos even though the twos come with an ace
+    let pairs = hands(&[("KK3", 10), ("A22", 1)]);
+    assert_eq!(score(&pairs, &rules), Ok(10 * 2 + 1));
+    let card_order = Ruleset {
+        tie_break: TieBreak::CardOrder,
+        ..rules
+    };
+    assert_eq!(score(&pairs, &card_order), Ok(10 + 2));
+}
+
+#[test]
+fn cards_outside_the_card_order() {
+    let rules = Ruleset::camel_cards();
+    assert_eq!(classify(b"KK3X2", &rules), None);
+    assert_eq!(
+        score(&hands(&[("KK3X2", 1)]), &rules),
+        Err("The hand KK3X2 has a card that isn't in the card order".to_string())
+    );
+
+    let mut day07 = Day07::default();
+    assert_eq!(
+        day07.set("part2.wildcards", "JX"),
+        Err("The wildcard X isn't in the card order".to_string())
+    );
+    assert!(day07.set("part2.card_order", "23456789TQKA").is_err());
+    assert_eq!(day07.part2_rules.wildcards, b"J");
+    assert_eq!(day07.part2_rules.card_order, b"J23456789TQKA");
+}
@@ -0,0 +1,42 @@
+use std::fs::read_to_string;
+
+use aoc2023::{
+    day08::{ghosts_meeting, Day08, Network},
+    Solution,
+};
+
+fn network(input: &str) -> Network {
+    Day08.parse(input).unwrap()
+}
+
+#[test]
+fn missing_or_unreachable_zzz() {
+    let ghosts = network(&read_to_string("tests/examples/day08b.txt").unwrap());
+    assert_eq!(
+        Day08.part1(&ghosts).to_string(),
+        "no answer: There's no node AAA"
+    );
+    let circle = network("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)");
+    assert_eq!(
+        Day08.part1(&circle).to_string(),
+        "no answer: ZZZ can't be reached from AAA"
+    );
+}
+
+#[test]
+fn ghosts_that_never_meet() {
+    // 11A is on a Z node only after step 1, 22A only after step 2
+    let network = network(
+        "L\n\n11A = (11Z, 11Z)\n11Z = (XXX, XXX)\n\
+         22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (XXX, XXX)\nXXX = (XXX, XXX)",
+    );
+    assert_eq!(
+        ghosts_meeting(&network),
+        Err(
+            "The ghosts never stand on Z nodes at the same time, the one starting at 11A \
+             stops reaching them"
+                .to_string()
+        )
+    );
+    assert!(Day08.part2(&network).to_string().starts_with("no answer: "));
+}
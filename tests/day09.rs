@@ -0,0 +1,73 @@
+use std::fs::read_to_string;
+
+use aoc2023::{
+    day09::{Day09, History},
+    math, Solution,
+};
+
+#[test]
+fn degrees_and_far_extrapolation() {
+    let input = read_to_string("tests/examples/day09.txt").unwrap();
+    let histories = Day09::default().parse(&input).unwrap();
+    let degrees: Vec<Option<usize>> = histories.iter().map(History::degree).collect();
+    assert_eq!(degrees, [Some(1), Some(2), Some(3)]);
+    assert_eq!(histories[0].ahead(1), Some(18));
+    assert_eq!(histories[2].behind(1), Some(5));
+    // 1 3 6 10 are the triangular numbers (k + 1) (k + 2) / 2
+    let k: i128 = 1_000_000_000_000;
+    assert_eq!(histories[1].at(k), Some((k + 1) * (k + 2) / 2));
+    assert_eq!(histories[1].at(-3), Some(1));
+    assert_eq!(histories[1].at(i128::MAX / 2), None);
+}
+
+#[test]
+fn matches_the_polynomial() {
+    let poly = |k: i128| 3 * k.pow(4) - 7 * k.pow(3) + k - 11;
+    let history = History::new((0..8).map(|k| poly(k) as i64).collect());
+    assert_eq!(history.degree(), Some(4));
+    for k in [-1_000_000, -5, 0, 9, 123_456_789] {
+        assert_eq!(history.at(k), Some(poly(k)));
+    }
+    assert_eq!(History::new(vec![0, 0, 0]).at(1 << 100), Some(0));
+}
+
+#[test]
+fn overflow_is_reported() {
+    // k choose 20, a history of degree 20
+    let values: Vec<String> = (0..30)
+        .map(|k| math::binomial(k, 20).unwrap().to_string())
+        .collect();
+    let day09 = Day09 {
+        steps: 100_000,
+        show_degrees: false,
+    };
+    let histories = day09.parse(&values.join(" ")).unwrap();
+    assert_eq!(histories[0].degree(), Some(20));
+    assert_eq!(
+        day09.part1(&histories).to_string(),
+        "no answer: The value of line 1 doesn't fit into an i128"
+    );
+}
+
+#[test]
+fn difference_table_overflow_is_reported() {
+    let values: Vec<String> = (0..70)
+        .map(|k| if k % 2 == 0 { i64::MAX } else { i64::MIN }.to_string())
+        .collect();
+    let day09 = Day09 {
+        steps: 1,
+        show_degrees: true,
+    };
+    let histories = day09.parse(&values.join(" ")).unwrap();
+    assert_eq!(histories[0].degree(), None);
+    assert_eq!(histories[0].at(0), None);
+    assert_eq!(
+        day09.part1(&histories).to_string(),
+        "no answer: The value of line 1 doesn't fit into an i128\n\
+         Line 1: differences don't fit into an i128"
+    );
+    assert_eq!(
+        day09.part2(&histories).to_string(),
+        "no answer: The value of line 1 doesn't fit into an i128"
+    );
+}
@@ -0,0 +1,31 @@
+use std::fs::read_to_string;
+
+use aoc2023::{
+    day10::{Day10, InsideMethod},
+    Solution,
+};
+
+#[test]
+fn start_off_the_loop() {
+    let day10 = Day10::default();
+    assert_eq!(day10.part1(&day10.parse("S-7\n|.|\nL-J").unwrap()), 4);
+    let error = day10
+        .parse(".....\n.S.7.\n.|.|.\n.L-J.\n.....")
+        .err()
+        .unwrap();
+    assert_eq!((error.line, error.column), (2, 2));
+    assert!(day10.parse("S").is_err());
+    let error = day10.parse("F7\nLJ").err().unwrap();
+    assert_eq!((error.line, error.column), (1, 1));
+    assert_eq!(error.message, "expected a start tile \"S\"");
+}
+
+#[test]
+fn inside_methods_agree() {
+    let input = read_to_string("tests/examples/day10a.txt").unwrap();
+    for inside_method in [InsideMethod::Sides, InsideMethod::Crossings] {
+        let day10 = Day10 { inside_method };
+        let maze = day10.parse(&input).unwrap();
+        assert_eq!(day10.part2(&maze), 10, "{inside_method:?}");
+    }
+}
@@ -0,0 +1,14 @@
+use aoc2023::{day13::Day13, Solution};
+
+#[test]
+fn no_reflection() {
+    let images = Day13.parse("#.\n..\n\n##\n..").unwrap();
+    assert_eq!(
+        Day13.part1(&images).to_string(),
+        "no answer: Pattern 1 has no reflection with a smudge count of 0"
+    );
+    assert_eq!(
+        Day13.part2(&images).to_string(),
+        "no answer: Pattern 2 has no reflection with a smudge count of 1"
+    );
+}
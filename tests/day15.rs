@@ -0,0 +1,17 @@
+use aoc2023::{day15::Day15, Solution};
+
+#[test]
+fn full_lens_box() {
+    // All labels go into box 0
+    let steps = Day15
+        .parse("aju=1,akd=2,ayv=3,aze=4,bhv=5,bie=6,bww=7")
+        .unwrap();
+    assert_eq!(
+        Day15.part2(&steps).to_string(),
+        "no answer: Box 0 can't hold more than 6 lenses"
+    );
+    let steps = Day15
+        .parse("aju=1,akd=2,ayv=3,aze=4,bhv=5,bie=6,aju-,bww=7")
+        .unwrap();
+    assert_eq!(Day15.part2(&steps).to_string(), "112");
+}
@@ -0,0 +1,90 @@
+use std::fs::read_to_string;
+
+use aoc2023::{
+    day17::{least_heat_loss, render_route, CrucibleRules, Day17},
+    Solution,
+};
+
+fn heat_loss(settings: &[(&str, &str)], input: &str) -> String {
+    let mut day17 = Day17::default();
+    for (name, value) in settings {
+        day17.set(name, value).unwrap();
+    }
+    let city = day17.parse(input).unwrap();
+    day17.part1(&city).to_string()
+}
+
+#[test]
+fn custom_rules() {
+    let input = read_to_string("tests/examples/day17.txt").unwrap();
+    assert_eq!(heat_loss(&[], &input), "102");
+    assert_eq!(heat_loss(&[("part1.turn_cost", "5")], &input), "159");
+    let longer = [("part1.max_straight", "5"), ("part1.min_straight", "2")];
+    assert_eq!(heat_loss(&longer, &input), "101");
+    let zigzag = [("part1.allow_reverse", "true"), ("part1.max_straight", "1")];
+    assert_eq!(heat_loss(&zigzag, &input), "133");
+}
+
+#[test]
+fn impossible_rules_and_cities() {
+    let mut day17 = Day17::default();
+    assert!(day17.set("part1.max_straight", "0").is_err());
+    assert!(day17.set("part1.min_straight", "5").is_err());
+    assert_eq!(heat_loss(&[], "7"), "0");
+    let city = day17.parse("123").unwrap();
+    assert_eq!(
+        day17.part2(&city).to_string(),
+        "no answer: The crucible can't reach the factory"
+    );
+}
+
+#[test]
+fn route_of_the_example() {
+    let day17 = Day17::default();
+    let city = day17
+        .parse(&read_to_string("tests/examples/day17.txt").unwrap())
+        .unwrap();
+    let (heat_loss, route) = least_heat_loss(&city, &CrucibleRules::CRUCIBLE).unwrap();
+    assert_eq!(heat_loss, 102);
+    assert_eq!((route[0].y, route[0].x, route[0].direction), (0, 0, None));
+    let factory = route.last().unwrap();
+    assert_eq!((factory.y, factory.x), (12, 12));
+    for pair in route.windows(2) {
+        let (from, to) = (pair[0], pair[1]);
+        assert_eq!(from.y.abs_diff(to.y) + from.x.abs_diff(to.x), 1);
+        assert!((1..=3).contains(&to.straight_steps));
+    }
+    let route_heat_loss: u32 = route[1..]
+        .iter()
+        .map(|node| city[(node.y as usize, node.x as usize)] as u32)
+        .sum();
+    assert_eq!(route_heat_loss, 102);
+}
+
+#[test]
+fn rendered_ultra_crucible_route() {
+    let input = "111111111111\n999999999991\n999999999991\n999999999991\n999999999991\n";
+    let day17 = Day17::default();
+    let city = day17.parse(input).unwrap();
+    let (heat_loss, route) = least_heat_loss(&city, &CrucibleRules::ULTRA_CRUCIBLE).unwrap();
+    assert_eq!(heat_loss, 71);
+    assert_eq!(
+        render_route(&city, &route).to_string(),
+        "1>>>>>>>1111\n\
+         9999999v9991\n\
+         9999999v9991\n\
+         9999999v9991\n\
+         9999999v>>>>\n"
+    );
+}
+
+#[test]
+fn rejected_settings_keep_the_rules() {
+    let mut day17 = Day17::default();
+    day17.set("part1.max_straight", "5").unwrap();
+    let rules = day17.part1_rules;
+    assert!(day17.set("part1.max_straight", "0").is_err());
+    assert!(day17.set("part1.min_straight", "6").is_err());
+    assert_eq!(day17.part1_rules, rules);
+    assert_eq!(day17.part2_rules, CrucibleRules::ULTRA_CRUCIBLE);
+}
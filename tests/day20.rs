@@ -0,0 +1,130 @@
+use std::{collections::HashMap, fs::read_to_string};
+
+use aoc2023::{
+    day20::{first_rx_low_pulse, Day20, Module, Simulator},
+    Solution,
+};
+
+fn simulator(name: &str) -> Simulator {
+    let input = read_to_string(format!("tests/examples/{name}.txt")).unwrap();
+    Simulator::new(&Day20::default().parse(&input).unwrap())
+}
+
+#[test]
+fn trace_matches_puzzle() {
+    let trace: Vec<String> = simulator("day20")
+        .trace(1)
+        .map(|signal| signal.to_string())
+        .collect();
+    assert_eq!(
+        trace,
+        [
+            "button -low-> broadcaster",
+            "broadcaster -low-> a",
+            "broadcaster -low-> b",
+            "broadcaster -low-> c",
+            "a -high-> b",
+            "b -high-> c",
+            "c -high-> inv",
+            "inv -low-> a",
+            "a -low-> b",
+            "b -low-> c",
+            "c -low-> inv",
+            "inv -high-> a",
+        ]
+    );
+}
+
+#[test]
+fn snapshot_and_stop() {
+    let mut simulator = simulator("day20a");
+    simulator.trace(2).for_each(drop);
+    assert_eq!(
+        simulator.snapshot().to_string(),
+        "After 2 presses:\na: off\nb: on\ncon: a=low, b=high\ninv: a=low"
+    );
+
+    let signal = simulator.run_until("output", true, 10).unwrap();
+    assert_eq!(signal.press, 3);
+    assert_eq!(signal.to_string(), "con -high-> output");
+}
+
+#[test]
+fn extra_module_types() {
+    let input = "broadcaster -> c, d\n#c/3 -> i\n!i -> out\n~d -> out\n";
+    let modules = Day20::default().parse(input).unwrap();
+    let mut simulator = Simulator::new(&modules);
+    let trace: Vec<String> = simulator
+        .trace(3)
+        .filter(|signal| signal.receiver_name == "out")
+        .map(|signal| format!("{} {signal}", signal.press))
+        .collect();
+    assert_eq!(
+        trace,
+        [
+            "1 d -low-> out",
+            "1 i -high-> out",
+            "2 d -low-> out",
+            "2 i -high-> out",
+            "3 d -low-> out",
+            "3 i -low-> out",
+        ]
+    );
+    assert_eq!(
+        simulator.snapshot().to_string(),
+        "After 3 presses:\nc: 0/3\nd: low"
+    );
+    assert!(Day20::default()
+        .parse("broadcaster -> c\n#c/0 -> c\n")
+        .is_err());
+}
+
+#[test]
+fn unknown_module_type() {
+    let e = Day20::default()
+        .parse("bradcaster -> a\n%a -> b\n")
+        .err()
+        .unwrap();
+    assert_eq!((e.line, e.column), (1, 1));
+    assert_eq!(e.text, "bradcaster");
+    assert_eq!(e.message, "unknown module type");
+    // Outputs without a declaration ignore their pulses
+    let modules = Day20::default()
+        .parse("broadcaster -> a\n%a -> b\n")
+        .unwrap();
+    assert!(modules["b"].outputs.is_empty());
+}
+
+fn day20(input: &str) -> (Day20, HashMap<String, Module>) {
+    let day20 = Day20 {
+        max_presses: 1000,
+        ..Day20::default()
+    };
+    let modules = day20.parse(input).unwrap();
+    (day20, modules)
+}
+
+#[test]
+fn rx_after_lcm_of_feeder_inputs() {
+    // y gets its first low pulse in press 3, z in press 4
+    let (day20, modules) = day20(
+        "broadcaster -> c, d\n#c/3 -> i\n!i -> y\n#d/4 -> j\n!j -> z\n\
+         !y -> x\n!z -> x\n&x -> rx",
+    );
+    assert_eq!(first_rx_low_pulse(&modules, 1000), Ok(12));
+    assert_eq!(day20.part2(&modules).to_string(), "12");
+}
+
+#[test]
+fn rx_never_gets_a_low_pulse() {
+    let (_, modules) = day20("broadcaster -> a\n%a -> x\n&x -> rx\n%b -> x");
+    assert_eq!(
+        first_rx_low_pulse(&modules, 1000),
+        Err("b got no low pulse in 1000 presses".to_string())
+    );
+    let (day20, modules) = day20(&read_to_string("tests/examples/day20.txt").unwrap());
+    assert_eq!(
+        day20.part2(&modules).to_string(),
+        "no answer: No module feeds rx"
+    );
+}
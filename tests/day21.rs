@@ -0,0 +1,34 @@
+use aoc2023::{day21::Day21, Solution};
+
+fn error(input: &str) -> (usize, String, String) {
+    let e = Day21::default().parse(input).err().unwrap();
+    (e.line, e.text, e.message)
+}
+
+#[test]
+fn grid_errors_point_at_a_row() {
+    let square = "expected a square grid".to_string();
+    assert_eq!(
+        error("...\n.S.\n...\n#.."),
+        (4, "#..".to_string(), square.clone())
+    );
+    assert_eq!(error("....\n.S..\n...."), (1, "....".to_string(), square));
+    assert_eq!(
+        error("#..\n...\n..."),
+        (
+            1,
+            "#..".to_string(),
+            "expected a start tile \"S\"".to_string()
+        )
+    );
+}
+
+#[test]
+fn counts_that_dont_grow_quadratically() {
+    let day21 = Day21::default();
+    let grid = day21.parse("S.#\n##.\n#..").unwrap();
+    assert_eq!(
+        day21.part2(&grid).to_string(),
+        "no answer: The reachable counts 1, 3 and 2 don't grow quadratically"
+    );
+}
@@ -0,0 +1,31 @@
+use aoc2023::{day23::Day23, Solution};
+
+fn error(input: &str) -> (usize, usize, String) {
+    let e = Day23.parse(input).err().unwrap();
+    (e.line, e.column, e.message)
+}
+
+#[test]
+fn missing_start_or_end() {
+    assert!(Day23.parse("#.#\n#.#").is_ok());
+    assert_eq!(
+        error("##.\n#.#"),
+        (
+            1,
+            2,
+            "expected a path next to the top left corner".to_string()
+        )
+    );
+    assert_eq!(
+        error("#.#\n#.#\n##."),
+        (
+            3,
+            2,
+            "expected a path next to the bottom right corner".to_string()
+        )
+    );
+    assert_eq!(
+        error("#.\n.."),
+        (1, 1, "expected at least 2 rows of 3 tiles".to_string())
+    );
+}
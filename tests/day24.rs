@@ -0,0 +1,11 @@
+use aoc2023::{day24::Day24, Solution};
+
+#[test]
+fn too_few_hailstones() {
+    let day24 = Day24::default();
+    let hail_stones = day24.parse("").unwrap();
+    assert_eq!(
+        day24.part2(&hail_stones).to_string(),
+        "no answer: Expected at least four hailstones"
+    );
+}
@@ -0,0 +1,18 @@
+use aoc2023::{day25::Day25, Solution};
+
+#[test]
+fn component_connected_to_itself() {
+    let error = Day25::default().parse("abc: def abc").unwrap_err();
+    assert_eq!(error.message, "expected another component");
+    assert_eq!(error.column, 10);
+}
+
+#[test]
+fn nothing_to_cut() {
+    let day25 = Day25::default();
+    let graph = day25.parse("").unwrap();
+    assert_eq!(
+        day25.part1(&graph).to_string(),
+        "no answer: There are fewer than two components to cut apart"
+    );
+}
@@ -0,0 +1,41 @@
+use aoc2023::grid::Grid;
+
+fn grid(text: &str) -> Grid<u8> {
+    Grid::parse(0, text, b"abcdef").unwrap()
+}
+
+#[test]
+fn transforms() {
+    let abc = grid("abc\ndef\n");
+    assert_eq!(abc.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(abc.rotate_cw().to_string(), "da\neb\nfc\n");
+    assert_eq!(abc.rotate_ccw().to_string(), "cf\nbe\nad\n");
+    assert_eq!(abc.rotate_cw().rotate_ccw(), abc);
+}
+
+#[test]
+fn neighbors_stay_inside() {
+    let abc = grid("abc\ndef\n");
+    assert_eq!(abc.neighbors4((0, 0)).collect::<Vec<_>>(), [(0, 1), (1, 0)]);
+    assert_eq!(
+        abc.neighbors8((1, 2)).collect::<Vec<_>>(),
+        [(0, 2), (1, 1), (0, 1)]
+    );
+    assert_eq!(abc.get((2, 0)), None);
+    assert_eq!(abc.column(1).map(|&b| b as char).collect::<String>(), "be");
+}
+
+#[test]
+fn parse_errors() {
+    let error = Grid::parse(3, "abc\nde", b"abcdef").unwrap_err();
+    assert_eq!((error.day, error.line, error.column), (3, 2, 1));
+    let error = Grid::parse(3, "abc\ndxf", b"abcdef").unwrap_err();
+    assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "x"));
+    assert!(Grid::parse(3, "", b"abcdef").is_err());
+}
+
+#[test]
+#[should_panic(expected = "column 3 is outside of the grid")]
+fn column_outside() {
+    grid("abc\ndef\n").column(3).for_each(drop);
+}
@@ -0,0 +1,40 @@
+use aoc2023::search;
+
+/// A line of nodes 0..=10, where stepping right costs 1 and jumping three to the right costs 2.
+fn line_neighbors(&node: &u32) -> Vec<(u32, u32)> {
+    [(node + 1, 1), (node + 3, 2)]
+        .into_iter()
+        .filter(|&(next, _)| next <= 10)
+        .collect()
+}
+
+#[test]
+fn dijkstra_finds_cheapest_path() {
+    let paths = search::dijkstra([0], line_neighbors, |&node| node == 9);
+    assert_eq!(paths.goal(), Some(&9));
+    assert_eq!(paths.goal_distance(), Some(6));
+    assert_eq!(paths.path_to(&9), Some(vec![0, 3, 6, 9]));
+}
+
+#[test]
+fn astar_agrees_with_dijkstra() {
+    let paths = search::astar(
+        [0],
+        line_neighbors,
+        |&node| (10 - node) / 3,
+        |&node| node == 10,
+    );
+    assert_eq!(paths.goal_distance(), Some(7));
+}
+
+#[test]
+fn bfs_counts_steps() {
+    let neighbors = |&node: &i32| [node - 1, node + 1];
+    let paths = search::bfs([0], neighbors, |&node| node == -4);
+    assert_eq!(paths.goal_distance(), Some(4));
+    assert_eq!(paths.path_to(&-2), Some(vec![0, -1, -2]));
+
+    let paths = search::bfs_within([0], neighbors, 3);
+    assert_eq!(paths.distances().len(), 7);
+    assert_eq!(paths.distance(&4), None);
+}